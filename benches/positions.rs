@@ -0,0 +1,60 @@
+//! Compares moving 100k points with the [`Positions`] buffer against per-entity [`Position<F32>`] components
+
+use bevy::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::position::{Position, Positions};
+use leafwing_2d::prelude::Velocity;
+
+const N_POINTS: usize = 100_000;
+const DELTA_SECONDS: f32 = 1.0 / 60.0;
+
+fn velocity(i: usize) -> Vec2 {
+    Vec2::new((i % 7) as f32, -((i % 5) as f32))
+}
+
+fn integrate_components(mut query: Query<(&mut Position<F32>, &Velocity<F32>)>) {
+    for (mut position, velocity) in query.iter_mut() {
+        *position += Vec2::new(velocity.x.0, velocity.y.0) * DELTA_SECONDS;
+    }
+}
+
+fn integrate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("integrate_100k");
+
+    let mut positions: Positions = (0..N_POINTS).map(|i| Vec2::new(i as f32, 0.0)).collect();
+    let velocities: Vec<Vec2> = (0..N_POINTS).map(velocity).collect();
+    group.bench_function("positions_buffer", |b| {
+        b.iter(|| positions.integrate(black_box(&velocities), DELTA_SECONDS))
+    });
+
+    let mut position_vec: Vec<Position<F32>> = (0..N_POINTS)
+        .map(|i| Position::new(i as f32, 0.0))
+        .collect();
+    group.bench_function("position_vec", |b| {
+        b.iter(|| {
+            for (position, &velocity) in position_vec.iter_mut().zip(black_box(&velocities)) {
+                *position += velocity * DELTA_SECONDS;
+            }
+        })
+    });
+
+    let mut world = World::new();
+    world.spawn_batch((0..N_POINTS).map(|i| {
+        let velocity = velocity(i);
+        (
+            Position::<F32>::new(i as f32, 0.0),
+            Velocity {
+                x: F32(velocity.x),
+                y: F32(velocity.y),
+            },
+        )
+    }));
+    let mut stage = SystemStage::single(integrate_components);
+    group.bench_function("position_components", |b| b.iter(|| stage.run(&mut world)));
+
+    group.finish();
+}
+
+criterion_group!(benches, integrate);
+criterion_main!(benches);
@@ -0,0 +1,81 @@
+//! Measures the cost of `sync_transform_with_2d` on a world where nothing moves
+//!
+//! `unfiltered` reproduces the query used before the `Changed` filters were added,
+//! which visited every entity and then found that none of their components had changed.
+
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+use leafwing_2d::continuous::F32;
+use leafwing_2d::plugin::{sync_transform_with_2d, SyncPolicy};
+use leafwing_2d::prelude::*;
+use leafwing_2d::transform::Plane;
+
+const N_ENTITIES: usize = 100_000;
+
+/// A copy of the pre-filter sync loop: every entity is visited, and skipped after checking for changes
+fn unfiltered_sync(
+    mut query: Query<
+        (
+            &mut Transform,
+            Option<&mut Rotation>,
+            Option<&mut Direction>,
+            Option<&mut Position<F32>>,
+        ),
+        Or<(With<Rotation>, With<Position<F32>>)>,
+    >,
+) {
+    for (transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut() {
+        let transform_changed = criterion::black_box(transform.is_changed());
+
+        if let Some(rotation) = maybe_rotation {
+            criterion::black_box((rotation.is_changed(), transform_changed));
+        }
+
+        if let Some(direction) = maybe_direction {
+            criterion::black_box((direction.is_changed(), transform_changed));
+        }
+
+        if let Some(position) = maybe_position {
+            criterion::black_box((position.is_changed(), transform_changed));
+        }
+    }
+}
+
+/// Spawns `N_ENTITIES` static entities, and runs `stage` once so that their spawn is no longer a change
+fn static_world(stage: &mut SystemStage) -> World {
+    let mut world = World::new();
+    world.insert_resource(SyncPolicy::default());
+    world.insert_resource(Plane::default());
+    world.spawn_batch((0..N_ENTITIES).map(|i| {
+        (
+            Position::<F32>::new(i as f32, 0.0),
+            Rotation::default(),
+            Direction::default(),
+            Transform::from_xyz(i as f32, 0.0, 0.0),
+        )
+    }));
+
+    stage.run(&mut world);
+    world
+}
+
+fn static_entities(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sync_transform_100k_static");
+
+    let mut filtered_stage = SystemStage::single(sync_transform_with_2d::<F32>);
+    let mut filtered_world = static_world(&mut filtered_stage);
+    group.bench_function("filtered", |b| {
+        b.iter(|| filtered_stage.run(&mut filtered_world))
+    });
+
+    let mut unfiltered_stage = SystemStage::single(unfiltered_sync);
+    let mut unfiltered_world = static_world(&mut unfiltered_stage);
+    group.bench_function("unfiltered", |b| {
+        b.iter(|| unfiltered_stage.run(&mut unfiltered_world))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, static_entities);
+criterion_main!(benches);
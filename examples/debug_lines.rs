@@ -0,0 +1,79 @@
+//! Demonstrates drawing the orientation of 2D entities with `TwoDDebugPlugin`
+//!
+//! Run with `cargo run --example debug_lines --features debug_lines`.
+//! Press Space to toggle the lines on and off, and Up / Down to change their length.
+
+use bevy::prelude::*;
+use leafwing_2d::debug::{OrientationDebugLines, TwoDDebugPlugin};
+use leafwing_2d::prelude::*;
+use leafwing_2d::orientation::Direction;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(TwoDPlugin::default())
+        .add_plugin(TwoDDebugPlugin {
+            enabled: true,
+            line_length: 80.0,
+        })
+        .add_startup_system(setup)
+        .add_system(spin)
+        .add_system(control_lines)
+        .run();
+}
+
+/// Entities with this component turn at a constant rate
+#[derive(Component)]
+struct Spin(Rotation);
+
+fn setup(mut commands: Commands) {
+    commands.spawn_bundle(OrthographicCameraBundle::new_2d());
+
+    // Turns by changing its `Rotation`
+    commands
+        .spawn_bundle(TransformBundle::default())
+        .insert_bundle(TwoDBundle::<F32> {
+            position: Position::new(-200.0, 0.0),
+            ..Default::default()
+        })
+        .insert(Spin(Rotation::from_degrees(1.0)));
+
+    // Faces a fixed `Direction`
+    commands
+        .spawn_bundle(TransformBundle::default())
+        .insert_bundle(TwoDBundle::<F32> {
+            position: Position::new(0.0, 0.0),
+            rotation: Rotation::SOUTHEAST,
+            direction: Direction::SOUTHEAST,
+            ..Default::default()
+        });
+
+    // Turns the other way, more quickly
+    commands
+        .spawn_bundle(TransformBundle::default())
+        .insert_bundle(TwoDBundle::<F32> {
+            position: Position::new(200.0, 0.0),
+            ..Default::default()
+        })
+        .insert(Spin(-Rotation::from_degrees(3.0)));
+}
+
+fn spin(mut query: Query<(&Spin, &mut Rotation)>) {
+    for (spin, mut rotation) in query.iter_mut() {
+        *rotation += spin.0;
+    }
+}
+
+fn control_lines(mut settings: ResMut<OrientationDebugLines>, input: Res<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::Space) {
+        settings.enabled = !settings.enabled;
+    }
+
+    if input.pressed(KeyCode::Up) {
+        settings.line_length += 2.0;
+    }
+
+    if input.pressed(KeyCode::Down) {
+        settings.line_length = (settings.line_length - 2.0).max(0.0);
+    }
+}
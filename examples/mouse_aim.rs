@@ -0,0 +1,82 @@
+//! Demonstrates rotating the player to face the mouse cursor
+//!
+//! The cursor's screen position is converted into world space, and then into a `Rotation`.
+//! The `TwoDPlugin` then synchronizes this `Rotation` with the player's `Transform`.
+
+use bevy::prelude::*;
+use leafwing_2d::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugin(TwoDPlugin::default())
+        .add_startup_system(setup)
+        .add_system(face_cursor)
+        .add_system(bevy::input::system::exit_on_esc_system)
+        .run();
+}
+
+#[derive(Component, Default)]
+struct Player;
+
+#[derive(Bundle, Default)]
+struct PlayerBundle {
+    player: Player,
+    #[bundle]
+    sprite: SpriteBundle,
+    #[bundle]
+    two_d: TwoDBundle<F32>,
+}
+
+fn setup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn_bundle(OrthographicCameraBundle::new_2d());
+
+    commands.spawn_bundle(PlayerBundle {
+        sprite: SpriteBundle {
+            texture: asset_server.load("textures/simplespace/ship_C.png"),
+            ..Default::default()
+        },
+        two_d: TwoDBundle {
+            position: Position::new(-100.0, 50.0),
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+}
+
+/// Converts the cursor's position on the primary window into world space
+///
+/// Returns `None` if the cursor is not over the window.
+fn cursor_world_position(
+    windows: &Windows,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Vec2> {
+    let window = windows.get_primary()?;
+    let screen_position = window.cursor_position()?;
+    let window_size = Vec2::new(window.width(), window.height());
+
+    // Convert from screen space to normalized device coordinates, which range from -1 to 1 on each axis
+    let ndc = (screen_position / window_size) * 2.0 - Vec2::ONE;
+    // Undo the camera's projection and transform to get back to world space
+    let ndc_to_world = camera_transform.compute_matrix() * camera.projection_matrix.inverse();
+    let world_position = ndc_to_world.project_point3(ndc.extend(-1.0));
+
+    Some(world_position.truncate())
+}
+
+fn face_cursor(
+    windows: Res<Windows>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut player_query: Query<(&Position<F32>, &mut Rotation), With<Player>>,
+) {
+    let (camera, camera_transform) = camera_query.single();
+    let (&position, mut rotation) = player_query.single_mut();
+
+    if let Some(cursor) = cursor_world_position(&windows, camera, camera_transform) {
+        // If the cursor is directly on top of the player, there's no way to face it: keep the current rotation
+        if let Ok(new_rotation) = position.rotation_to_cursor(cursor) {
+            *rotation = new_rotation;
+        }
+    }
+}
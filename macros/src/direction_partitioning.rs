@@ -0,0 +1,101 @@
+use crate::leafwing_2d_path;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Fields, Lit, Meta, NestedMeta};
+
+pub(crate) fn direction_partitioning_inner(ast: &DeriveInput) -> TokenStream {
+    // Splitting the abstract syntax tree
+    let enum_name = ast.ident.clone();
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    let crate_path = leafwing_2d_path();
+
+    // Fetch the variants
+    let data_enum = match &ast.data {
+        Data::Enum(data_enum) => data_enum,
+        _ => panic!("DirectionParitioning can only be derived for enum types."),
+    };
+
+    assert!(
+        !data_enum.variants.is_empty(),
+        "At least one variant must be provided."
+    );
+
+    let mut variant_identifiers = Vec::new();
+    let mut variant_degrees = Vec::new();
+
+    for variant in data_enum.variants.iter() {
+        assert!(
+            matches!(variant.fields, Fields::Unit),
+            "Only unit variants can be used."
+        );
+
+        variant_identifiers.push(variant.ident.clone());
+        variant_degrees.push(partition_degrees(&variant.attrs).unwrap_or_else(|| {
+            panic!(
+                "The {} variant must be annotated with `#[partition(degrees = ...)]`.",
+                variant.ident
+            )
+        }));
+    }
+
+    quote! {
+        impl #impl_generics #crate_path::partitioning::DirectionParitioning for #enum_name #type_generics #where_clause {
+            fn partitions() -> Vec<Self> {
+                vec![#(#enum_name::#variant_identifiers),*]
+            }
+        }
+
+        impl #impl_generics From<#enum_name #type_generics> for #crate_path::orientation::Rotation #where_clause {
+            fn from(partition: #enum_name #type_generics) -> #crate_path::orientation::Rotation {
+                match partition {
+                    #(#enum_name::#variant_identifiers => #crate_path::orientation::Rotation::from_degrees(#variant_degrees),)*
+                }
+            }
+        }
+
+        impl #impl_generics From<#enum_name #type_generics> for #crate_path::orientation::Direction #where_clause {
+            fn from(partition: #enum_name #type_generics) -> #crate_path::orientation::Direction {
+                let rotation: #crate_path::orientation::Rotation = partition.into();
+                rotation.into()
+            }
+        }
+
+        impl #impl_generics From<#enum_name #type_generics> for #crate_path::__private::Vec2 #where_clause {
+            fn from(partition: #enum_name #type_generics) -> #crate_path::__private::Vec2 {
+                let rotation: #crate_path::orientation::Rotation = partition.into();
+                rotation.into()
+            }
+        }
+    }
+}
+
+/// Extracts the `degrees` value from a `#[partition(degrees = ...)]` attribute, as an [`f32`]
+fn partition_degrees(attributes: &[Attribute]) -> Option<f32> {
+    let attribute = attributes
+        .iter()
+        .find(|attribute| attribute.path.is_ident("partition"))?;
+
+    let nested = match attribute.parse_meta() {
+        Ok(Meta::List(meta_list)) => meta_list.nested,
+        _ => panic!("Expected an attribute of the form `#[partition(degrees = ...)]`."),
+    };
+
+    nested.iter().find_map(|nested_meta| match nested_meta {
+        NestedMeta::Meta(Meta::NameValue(name_value)) if name_value.path.is_ident("degrees") => {
+            match &name_value.lit {
+                Lit::Float(float) => Some(
+                    float
+                        .base10_parse::<f32>()
+                        .expect("`degrees` must be a valid f32."),
+                ),
+                Lit::Int(int) => Some(
+                    int.base10_parse::<f32>()
+                        .expect("`degrees` must be a valid f32."),
+                ),
+                _ => panic!("`degrees` must be a number."),
+            }
+        }
+        _ => None,
+    })
+}
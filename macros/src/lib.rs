@@ -5,9 +5,13 @@
 //! Copyright (c) 2019 Peter Glotfelty under the MIT License
 
 extern crate proc_macro;
+mod direction_partitioning;
 mod trivial_coordinate;
 use proc_macro::TokenStream;
-use syn::DeriveInput;
+use proc_macro2::Span;
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{DeriveInput, Ident};
 
 #[proc_macro_derive(TrivialCoordinate)]
 pub fn trivialcoordinate(input: TokenStream) -> TokenStream {
@@ -15,3 +19,34 @@ pub fn trivialcoordinate(input: TokenStream) -> TokenStream {
 
     crate::trivial_coordinate::trivial_coordinate_inner(&ast).into()
 }
+
+#[proc_macro_derive(DirectionParitioning, attributes(partition))]
+pub fn direction_partitioning(input: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    crate::direction_partitioning::direction_partitioning_inner(&ast).into()
+}
+
+/// The path to the `leafwing_2d` crate, as seen from the crate invoking the macro
+pub(crate) fn leafwing_2d_path() -> proc_macro2::TokenStream {
+    if let Ok(found_crate) = crate_name("leafwing_2d") {
+        // The crate was found in the Cargo.toml
+        match found_crate {
+            FoundCrate::Itself => quote!(leafwing_2d),
+            FoundCrate::Name(name) => {
+                let ident = Ident::new(&name, Span::call_site());
+                quote!(#ident)
+            }
+        }
+    } else {
+        // The crate was not found in the Cargo.toml,
+        // so we assume that we are in the owning_crate itself
+        //
+        // In order for this to play nicely with unit tests within the crate itself,
+        // `use crate as leafwing_2d` at the top of each test module where this macro is needed
+        //
+        // Note that doc tests, integration tests and examples want the full standard import,
+        // as they are evaluated as if they were external
+        quote!(leafwing_2d)
+    }
+}
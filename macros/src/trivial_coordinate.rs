@@ -1,34 +1,14 @@
-use proc_macro2::Span;
+use crate::leafwing_2d_path;
 use proc_macro2::TokenStream;
-use proc_macro_crate::{crate_name, FoundCrate};
 use quote::quote;
-use syn::{Data, DeriveInput, Ident};
+use syn::{Data, DeriveInput};
 
 pub(crate) fn trivial_coordinate_inner(ast: &DeriveInput) -> TokenStream {
     // Splitting the abstract syntax tree
     let struct_name = ast.ident.clone();
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let crate_path = if let Ok(found_crate) = crate_name("leafwing_2d") {
-        // The crate was found in the Cargo.toml
-        match found_crate {
-            FoundCrate::Itself => quote!(leafwing_2d),
-            FoundCrate::Name(name) => {
-                let ident = Ident::new(&name, Span::call_site());
-                quote!(#ident)
-            }
-        }
-    } else {
-        // The crate was not found in the Cargo.toml,
-        // so we assume that we are in the owning_crate itself
-        //
-        // In order for this to play nicely with unit tests within the crate itself,
-        // `use crate as leafwing_2d` at the top of each test module where this macro is needed
-        //
-        // Note that doc tests, integration tests and examples want the full standard import,
-        // as they are evaluated as if they were external
-        quote!(leafwing_2d)
-    };
+    let crate_path = leafwing_2d_path();
 
     // Fetch the wrapped field
     let data_struct = match &ast.data {
@@ -0,0 +1,92 @@
+//! Implementations of the [`approx`] traits, enabled by the `approx` feature
+//!
+//! [`Rotation`] and [`Direction`] are compared by the angle between them,
+//! using a [`Rotation`] as their epsilon.
+//! [`Position`] is compared axis-by-axis after converting each coordinate into [`f32`].
+
+use crate::coordinate::Coordinate;
+use crate::orientation::{Direction, Orientation, Rotation};
+use crate::position::Position;
+use approx::{AbsDiffEq, RelativeEq};
+
+impl AbsDiffEq for Rotation {
+    type Epsilon = Rotation;
+
+    /// Matches the tolerance of [`Orientation::assert_approx_eq`]
+    fn default_epsilon() -> Rotation {
+        Rotation::new(2)
+    }
+
+    fn abs_diff_eq(&self, other: &Rotation, epsilon: Rotation) -> bool {
+        self.distance(*other) <= epsilon
+    }
+}
+
+impl RelativeEq for Rotation {
+    fn default_max_relative() -> Rotation {
+        Rotation::default_epsilon()
+    }
+
+    /// Angles wrap around, so a relative comparison is meaningless: `max_relative` is ignored
+    fn relative_eq(&self, other: &Rotation, epsilon: Rotation, _max_relative: Rotation) -> bool {
+        self.abs_diff_eq(other, epsilon)
+    }
+}
+
+impl AbsDiffEq for Direction {
+    type Epsilon = Rotation;
+
+    /// Matches the tolerance of [`Orientation::assert_approx_eq`]
+    fn default_epsilon() -> Rotation {
+        Rotation::new(2)
+    }
+
+    /// [`Direction::NEUTRAL`] is only approximately equal to itself
+    fn abs_diff_eq(&self, other: &Direction, epsilon: Rotation) -> bool {
+        if self.is_neutral() || other.is_neutral() {
+            return self.is_neutral() && other.is_neutral();
+        }
+
+        self.distance(*other) <= epsilon
+    }
+}
+
+impl RelativeEq for Direction {
+    fn default_max_relative() -> Rotation {
+        Direction::default_epsilon()
+    }
+
+    /// Angles wrap around, so a relative comparison is meaningless: `max_relative` is ignored
+    fn relative_eq(&self, other: &Direction, epsilon: Rotation, _max_relative: Rotation) -> bool {
+        self.abs_diff_eq(other, epsilon)
+    }
+}
+
+impl<C: Coordinate> AbsDiffEq for Position<C> {
+    type Epsilon = f32;
+
+    fn default_epsilon() -> f32 {
+        f32::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Position<C>, epsilon: f32) -> bool {
+        let (self_x, self_y): (f32, f32) = (self.x.into(), self.y.into());
+        let (other_x, other_y): (f32, f32) = (other.x.into(), other.y.into());
+
+        self_x.abs_diff_eq(&other_x, epsilon) && self_y.abs_diff_eq(&other_y, epsilon)
+    }
+}
+
+impl<C: Coordinate> RelativeEq for Position<C> {
+    fn default_max_relative() -> f32 {
+        f32::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Position<C>, epsilon: f32, max_relative: f32) -> bool {
+        let (self_x, self_y): (f32, f32) = (self.x.into(), self.y.into());
+        let (other_x, other_y): (f32, f32) = (other.x.into(), other.y.into());
+
+        self_x.relative_eq(&other_x, epsilon, max_relative)
+            && self_y.relative_eq(&other_y, epsilon, max_relative)
+    }
+}
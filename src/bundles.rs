@@ -11,6 +11,10 @@ use bevy_ecs::prelude::Bundle;
 
 /// A [`Bundle`] of components that store 2-dimensional information about position and orientation
 ///
+/// By default, the entity faces north: the `rotation` is [`Rotation::NORTH`] and the `direction` is [`Direction::NORTH`].
+/// These agree with each other, so the default bundle does not need to be reconciled by
+/// [`sync_direction_and_rotation`](crate::plugin::sync_direction_and_rotation) when it is spawned.
+///
 /// # Example
 /// ```rust
 /// use bevy::prelude::*;
@@ -0,0 +1,265 @@
+//! Human-readable compass labels for [`Rotation`]
+//!
+//! Unlike the types in [`partitioning`](crate::partitioning), these are purely for display:
+//! use [`Rotation::compass8`] or [`Rotation::compass16`] to find the name of the nearest compass point.
+//!
+//! The same names can be parsed back into a [`Rotation`] or [`Direction`] using [`FromStr`].
+
+use crate::errors::ParseOrientationError;
+use crate::orientation::{Direction, Rotation};
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// The 8 points of a compass rose
+///
+/// [`Display`] prints the standard abbreviation, such as `NE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass8 {
+    /// North
+    N,
+    /// Northeast
+    NE,
+    /// East
+    E,
+    /// Southeast
+    SE,
+    /// South
+    S,
+    /// Southwest
+    SW,
+    /// West
+    W,
+    /// Northwest
+    NW,
+}
+
+impl Compass8 {
+    /// Each compass point, in clockwise order from north
+    pub const POINTS: [Compass8; 8] = {
+        use Compass8::*;
+
+        [N, NE, E, SE, S, SW, W, NW]
+    };
+}
+
+/// The 16 points of a compass rose
+///
+/// [`Display`] prints the standard abbreviation, such as `NNE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compass16 {
+    /// North
+    N,
+    /// North-northeast
+    NNE,
+    /// Northeast
+    NE,
+    /// East-northeast
+    ENE,
+    /// East
+    E,
+    /// East-southeast
+    ESE,
+    /// Southeast
+    SE,
+    /// South-southeast
+    SSE,
+    /// South
+    S,
+    /// South-southwest
+    SSW,
+    /// Southwest
+    SW,
+    /// West-southwest
+    WSW,
+    /// West
+    W,
+    /// West-northwest
+    WNW,
+    /// Northwest
+    NW,
+    /// North-northwest
+    NNW,
+}
+
+impl Compass16 {
+    /// Each compass point, in clockwise order from north
+    pub const POINTS: [Compass16; 16] = {
+        use Compass16::*;
+
+        [
+            N, NNE, NE, ENE, E, ESE, SE, SSE, S, SSW, SW, WSW, W, WNW, NW, NNW,
+        ]
+    };
+}
+
+impl Rotation {
+    /// The name of the nearest of the 8 compass points
+    ///
+    /// Rotations exactly halfway between two points are labelled with the clockwise point.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::compass::Compass8;
+    /// use leafwing_2d::orientation::Rotation;
+    ///
+    /// assert_eq!(Rotation::from_degrees(22.4).compass8(), Compass8::N);
+    /// assert_eq!(Rotation::from_degrees(22.6).compass8(), Compass8::NE);
+    /// assert_eq!(Rotation::from_degrees(350.0).compass8(), Compass8::N);
+    /// assert_eq!(Rotation::WEST.compass8().to_string(), "W");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn compass8(self) -> Compass8 {
+        Compass8::POINTS[self.nearest_compass_point(8)]
+    }
+
+    /// The name of the nearest of the 16 compass points
+    ///
+    /// Rotations exactly halfway between two points are labelled with the clockwise point.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::compass::Compass16;
+    /// use leafwing_2d::orientation::Rotation;
+    ///
+    /// assert_eq!(Rotation::from_degrees(11.2).compass16(), Compass16::N);
+    /// assert_eq!(Rotation::from_degrees(11.3).compass16(), Compass16::NNE);
+    /// assert_eq!(Rotation::from_degrees(22.4).compass16(), Compass16::NNE);
+    /// assert_eq!(Rotation::from_degrees(200.0).compass16().to_string(), "SSW");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn compass16(self) -> Compass16 {
+        Compass16::POINTS[self.nearest_compass_point(16)]
+    }
+
+    /// The index of the nearest of `n_points` evenly spaced compass points, clockwise from north
+    fn nearest_compass_point(self, n_points: u32) -> usize {
+        // Doubled to keep the half-width of each point in integer arithmetic
+        let doubled_width = 2 * Rotation::FULL_CIRCLE as u32 / n_points;
+        let doubled_deci_degrees = 2 * self.deci_degrees() as u32;
+
+        ((doubled_deci_degrees + doubled_width / 2) / doubled_width % n_points) as usize
+    }
+}
+
+impl From<Compass8> for Rotation {
+    fn from(point: Compass8) -> Rotation {
+        Rotation::new(point as u16 * (Rotation::FULL_CIRCLE / 8))
+    }
+}
+
+impl From<Compass16> for Rotation {
+    fn from(point: Compass16) -> Rotation {
+        Rotation::new(point as u16 * (Rotation::FULL_CIRCLE / 16))
+    }
+}
+
+impl Display for Compass8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl Display for Compass16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// The full names of the 8 points of a compass rose, in clockwise order from north
+const COMPASS8_NAMES: [&str; 8] = [
+    "north",
+    "northeast",
+    "east",
+    "southeast",
+    "south",
+    "southwest",
+    "west",
+    "northwest",
+];
+
+/// Parses a compass point or an angle
+///
+/// The following forms are accepted, ignoring case and surrounding whitespace:
+/// - compass abbreviations, such as `"N"`, `"ne"` or `"SSW"`
+/// - the full names of the 8 compass points, such as `"north"` or `"South-West"`
+/// - angles in degrees clockwise from north, such as `"90deg"` or `"90°"` (as printed by [`Display`])
+/// - angles in radians clockwise from north, such as `"1.5rad"`
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::orientation::{Orientation, Rotation};
+///
+/// assert_eq!("NE".parse::<Rotation>().unwrap(), Rotation::NORTHEAST);
+/// assert_eq!("west".parse::<Rotation>().unwrap(), Rotation::WEST);
+/// assert_eq!("45deg".parse::<Rotation>().unwrap(), Rotation::NORTHEAST);
+/// "3.1416rad".parse::<Rotation>().unwrap().assert_approx_eq(Rotation::SOUTH);
+///
+/// assert!("sideways".parse::<Rotation>().is_err());
+/// ```
+impl FromStr for Rotation {
+    type Err = ParseOrientationError;
+
+    fn from_str(s: &str) -> Result<Rotation, ParseOrientationError> {
+        let error = || ParseOrientationError {
+            input: s.to_string(),
+        };
+
+        let trimmed = s.trim();
+
+        if let Some(&point) = Compass16::POINTS
+            .iter()
+            .find(|point| point.to_string().eq_ignore_ascii_case(trimmed))
+        {
+            return Ok(point.into());
+        }
+
+        let lowercase = trimmed.to_lowercase();
+
+        // Separators are allowed within names, such as "south-west"
+        let name: String = lowercase
+            .chars()
+            .filter(|c| !matches!(c, '-' | '_' | ' '))
+            .collect();
+        if let Some(index) = COMPASS8_NAMES.iter().position(|&point| point == name) {
+            return Ok(Compass8::POINTS[index].into());
+        }
+
+        let parse_angle = |number: &str| -> Result<f32, ParseOrientationError> {
+            number
+                .trim()
+                .parse::<f32>()
+                .ok()
+                .filter(|angle| angle.is_finite())
+                .ok_or_else(error)
+        };
+
+        if let Some(degrees) = lowercase
+            .strip_suffix("deg")
+            .or_else(|| lowercase.strip_suffix('°'))
+        {
+            Ok(Rotation::from_degrees(parse_angle(degrees)?))
+        } else if let Some(radians) = lowercase.strip_suffix("rad") {
+            Ok(Rotation::from_radians(parse_angle(radians)?))
+        } else {
+            Err(error())
+        }
+    }
+}
+
+/// Parses a compass point or an angle, in the same forms as [`Rotation`]
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::orientation::Direction;
+///
+/// assert_eq!("SW".parse::<Direction>().unwrap(), Direction::SOUTHWEST);
+/// ```
+impl FromStr for Direction {
+    type Err = ParseOrientationError;
+
+    fn from_str(s: &str) -> Result<Direction, ParseOrientationError> {
+        s.parse::<Rotation>().map(Direction::from)
+    }
+}
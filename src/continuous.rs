@@ -2,6 +2,7 @@
 
 use crate as leafwing_2d;
 use crate::coordinate::{Coordinate, TrivialCoordinate};
+use core::ops::*;
 
 /// A [`f32`]-backed [`Coordinate`]
 #[derive(TrivialCoordinate)]
@@ -18,3 +19,152 @@ impl Coordinate for F32 {
     const DATA_ZERO: f32 = 0.;
     const DATA_ONE: f32 = 1.;
 }
+
+/// A [`f64`]-backed [`Coordinate`], for large worlds where [`F32`] is not precise enough
+///
+/// Far from the origin, adjacent [`f32`] values are spaced widely apart, causing movement to jitter or stall.
+/// [`Position<F64>`](crate::position::Position) keeps full [`f64`] precision when positions are added, subtracted or scaled.
+///
+/// However, [`Transform`](bevy_transform::components::Transform) (and the distance helpers on [`Position`](crate::position::Position))
+/// work in [`f32`], and so these conversions round to the nearest [`f32`].
+/// As a result, the [`TwoDPlugin`](crate::plugin::TwoDPlugin) synchronizes the rounded value onto the [`Transform`],
+/// and reading a [`Transform`] back into a [`Position<F64>`](crate::position::Position) can never be more precise than the [`Transform`] itself.
+/// Keep the [`Position`](crate::position::Position) authoritative (the default [`SyncPolicy`](crate::plugin::SyncPolicy)) so that this precision is not lost.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::{F32, F64};
+/// use leafwing_2d::position::Position;
+///
+/// let far_away = Position::<F64>::new(1e9, 0.0);
+/// let step = Position::<F64>::new(0.25, 0.0);
+/// assert_eq!((far_away + step).x, F64(1e9 + 0.25));
+///
+/// // The same step is lost entirely when using `f32`
+/// let far_away = Position::<F32>::new(1e9, 0.0);
+/// let step = Position::<F32>::new(0.25, 0.0);
+/// assert_eq!((far_away + step).x, F32(1e9));
+/// ```
+#[derive(TrivialCoordinate)]
+pub struct F64(pub f64);
+
+impl Coordinate for F64 {
+    type Data = f64;
+
+    const COORD_TO_TRANSFORM: f32 = 1.0;
+    const MIN: Self = F64(f64::MIN);
+    const MAX: Self = F64(f64::MAX);
+    const ZERO: Self = F64(0.0);
+
+    const DATA_ZERO: f64 = 0.;
+    const DATA_ONE: f64 = 1.;
+}
+
+impl From<f32> for F64 {
+    fn from(value: f32) -> F64 {
+        F64(value as f64)
+    }
+}
+
+/// Rounds to the nearest [`f32`], saturating to infinity for values beyond the range of [`f32`]
+impl From<F64> for f32 {
+    fn from(coordinate: F64) -> f32 {
+        coordinate.0 as f32
+    }
+}
+
+/// A [`f32`]-backed [`Coordinate`] that is clamped to lie within `MIN..=MAX`
+///
+/// Every conversion and arithmetic operation saturates at the bounds,
+/// so a [`Position`](crate::position::Position) using this coordinate can never leave the play field.
+/// This includes values read back from a [`Transform`](bevy_transform::components::Transform)
+/// by the [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+///
+/// `MIN` should be less than or equal to 0, and `MAX` should be greater than or equal to 0,
+/// so that [`Coordinate::ZERO`] lies within the bounds.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::continuous::Saturating;
+///
+/// type Bounded = Saturating<-10, 10>;
+///
+/// assert_eq!(Bounded::from(3.5).value(), 3.5);
+/// assert_eq!(Bounded::from(42.0).value(), 10.0);
+/// assert_eq!((Bounded::from(-8.0) - Bounded::from(5.0)).value(), -10.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Saturating<const MIN: isize, const MAX: isize>(f32);
+
+impl<const MIN: isize, const MAX: isize> Saturating<MIN, MAX> {
+    /// Creates a new [`Saturating`] coordinate, clamping `value` to lie within `MIN..=MAX`
+    #[inline]
+    #[must_use]
+    pub fn new(value: f32) -> Self {
+        Self(value.clamp(MIN as f32, MAX as f32))
+    }
+
+    /// The wrapped value, which is always within `MIN..=MAX`
+    #[inline]
+    #[must_use]
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> Coordinate for Saturating<MIN, MAX> {
+    type Data = f32;
+
+    const COORD_TO_TRANSFORM: f32 = 1.0;
+    const MIN: Self = Saturating(MIN as f32);
+    const MAX: Self = Saturating(MAX as f32);
+    const ZERO: Self = Saturating(0.0);
+
+    const DATA_ZERO: f32 = 0.;
+    const DATA_ONE: f32 = 1.;
+}
+
+impl<const MIN: isize, const MAX: isize> From<f32> for Saturating<MIN, MAX> {
+    fn from(value: f32) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: isize, const MAX: isize> From<Saturating<MIN, MAX>> for f32 {
+    fn from(coordinate: Saturating<MIN, MAX>) -> f32 {
+        coordinate.0
+    }
+}
+
+/// Implements a binary operator (and its assigning form) that saturates at the bounds
+macro_rules! impl_saturating_op {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<const MIN: isize, const MAX: isize> $trait for Saturating<MIN, MAX> {
+            type Output = Self;
+
+            fn $method(self, other: Self) -> Self {
+                Self::new(self.0 $op other.0)
+            }
+        }
+
+        impl<const MIN: isize, const MAX: isize> $trait<f32> for Saturating<MIN, MAX> {
+            type Output = Self;
+
+            fn $method(self, other: f32) -> Self {
+                Self::new(self.0 $op other)
+            }
+        }
+
+        impl<const MIN: isize, const MAX: isize> $assign_trait for Saturating<MIN, MAX> {
+            fn $assign_method(&mut self, other: Self) {
+                *self = Self::new(self.0 $op other.0);
+            }
+        }
+    };
+}
+
+impl_saturating_op!(Add, add, AddAssign, add_assign, +);
+impl_saturating_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_saturating_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_saturating_op!(Div, div, DivAssign, div_assign, /);
+impl_saturating_op!(Rem, rem, RemAssign, rem_assign, %);
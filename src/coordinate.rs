@@ -1,5 +1,6 @@
 //! Traits to definite units that define distances
 
+use crate::errors::CoordinateConversionError;
 use crate::position::Position;
 pub use leafwing_2d_macros::TrivialCoordinate;
 use std::{fmt::Debug, ops::*};
@@ -90,6 +91,41 @@ pub trait Coordinate:
         y: Self::ZERO,
     };
 
+    /// Converts an [`f32`] into this coordinate type, checking that no data is lost
+    ///
+    /// The infallible [`From<f32>`] conversion (which is used when synchronizing with [`Transform`](bevy_transform::components::Transform))
+    /// silently saturates or produces garbage for values that cannot be represented.
+    /// Instead, this returns an error if `float` is not finite, or lies outside of the range between [`Coordinate::MIN`] and [`Coordinate::MAX`].
+    /// Values within the range are still rounded to the nearest representable coordinate, as usual.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::continuous::Saturating;
+    /// use leafwing_2d::coordinate::Coordinate;
+    /// use leafwing_2d::errors::CoordinateConversionError;
+    ///
+    /// type Bounded = Saturating<-10, 10>;
+    ///
+    /// assert_eq!(Bounded::try_from_f32(3.0), Ok(Bounded::from(3.0)));
+    /// assert_eq!(
+    ///     Bounded::try_from_f32(40.0),
+    ///     Err(CoordinateConversionError::OutOfRange { value: 40.0 })
+    /// );
+    /// ```
+    fn try_from_f32(float: f32) -> Result<Self, CoordinateConversionError> {
+        if !float.is_finite() {
+            return Err(CoordinateConversionError::NonFinite { value: float });
+        }
+
+        let min: f32 = Self::MIN.into();
+        let max: f32 = Self::MAX.into();
+        if float < min || float > max {
+            return Err(CoordinateConversionError::OutOfRange { value: float });
+        }
+
+        Ok(Self::from(float))
+    }
+
     /// Checks that [`Coordinate::MIN`] and [`Coordinate::MAX`] can be converted to and from `f32` in a reasonable fashion
     ///
     /// This should not panic, and the min value should be less than the max in both forms.
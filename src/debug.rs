@@ -0,0 +1,122 @@
+//! Visual debugging tools for 2D orientation
+//!
+//! Requires the `debug_lines` feature, which uses [`bevy_prototype_debug_lines`] to draw.
+
+use crate::orientation::{Direction, Rotation};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_math::Vec3;
+use bevy_prototype_debug_lines::{DebugLines, DebugLinesPlugin};
+use bevy_render::color::Color;
+use bevy_transform::components::GlobalTransform;
+
+/// Draws lines that show which way each 2D entity is facing
+///
+/// Each line starts at the entity's [`GlobalTransform`] and points along one of its orientations:
+/// - [`Direction`] is drawn in [`TwoDDebugPlugin::DIRECTION_COLOR`]
+/// - [`Rotation`] is drawn in [`TwoDDebugPlugin::ROTATION_COLOR`]
+/// - the rotation of the [`GlobalTransform`] is drawn in [`TwoDDebugPlugin::TRANSFORM_COLOR`]
+///
+/// When everything is in sync, these lines overlap perfectly.
+/// Any visible fan of lines indicates a desync between the components.
+///
+/// The settings are stored in the [`OrientationDebugLines`] resource, and can be modified at run time.
+///
+/// # Example
+/// ```rust,no_run
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::debug::TwoDDebugPlugin;
+///
+/// App::new()
+///     .add_plugins(DefaultPlugins)
+///     .add_plugin(TwoDPlugin::default())
+///     .add_plugin(TwoDDebugPlugin {
+///         enabled: true,
+///         line_length: 100.0,
+///     })
+///     .run();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TwoDDebugPlugin {
+    /// Should the orientation lines be drawn?
+    ///
+    /// Default: [`true`](bool)
+    pub enabled: bool,
+    /// How long should each line be, in [`Transform`](bevy_transform::components::Transform) units?
+    ///
+    /// Default: 50.0
+    pub line_length: f32,
+}
+
+impl TwoDDebugPlugin {
+    /// The color used to draw each entity's [`Direction`]
+    pub const DIRECTION_COLOR: Color = Color::GREEN;
+    /// The color used to draw each entity's [`Rotation`]
+    pub const ROTATION_COLOR: Color = Color::YELLOW;
+    /// The color used to draw the facing of each entity's [`GlobalTransform`]
+    pub const TRANSFORM_COLOR: Color = Color::CYAN;
+}
+
+impl Default for TwoDDebugPlugin {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            line_length: 50.0,
+        }
+    }
+}
+
+impl Plugin for TwoDDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(DebugLinesPlugin::default())
+            .insert_resource(OrientationDebugLines {
+                enabled: self.enabled,
+                line_length: self.line_length,
+            })
+            // Drawing at the very end of the frame ensures that the sync systems have already run
+            .add_system_to_stage(CoreStage::Last, draw_orientation_lines);
+    }
+}
+
+/// The run-time settings for [`TwoDDebugPlugin`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OrientationDebugLines {
+    /// Should the orientation lines be drawn?
+    pub enabled: bool,
+    /// How long should each line be, in [`Transform`](bevy_transform::components::Transform) units?
+    pub line_length: f32,
+}
+
+/// Draws a line along the [`Direction`], [`Rotation`] and [`GlobalTransform`] rotation of each entity
+///
+/// [`Direction::NEUTRAL`] does not point anywhere, and so is not drawn.
+pub fn draw_orientation_lines(
+    settings: Res<OrientationDebugLines>,
+    mut lines: ResMut<DebugLines>,
+    query: Query<(&GlobalTransform, Option<&Direction>, Option<&Rotation>)>,
+) {
+    if !settings.enabled {
+        return;
+    }
+
+    for (transform, maybe_direction, maybe_rotation) in query.iter() {
+        let start = transform.translation;
+        let mut draw = |direction: Direction, color: Color| {
+            let end = start + Vec3::from(direction) * settings.line_length;
+            // A duration of 0 means that the line only lasts for a single frame
+            lines.line_colored(start, end, 0.0, color);
+        };
+
+        if let Some(&direction) = maybe_direction.filter(|direction| !direction.is_neutral()) {
+            draw(direction, TwoDDebugPlugin::DIRECTION_COLOR);
+        }
+
+        if let Some(&rotation) = maybe_rotation {
+            draw(rotation.into(), TwoDDebugPlugin::ROTATION_COLOR);
+        }
+
+        draw(transform.rotation.into(), TwoDDebugPlugin::TRANSFORM_COLOR);
+    }
+}
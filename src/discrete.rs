@@ -79,6 +79,74 @@ pub trait DiscreteCoordinate: Coordinate {
     }
 }
 
+impl<C: DiscreteCoordinate> Position<C>
+where
+    C::Data: TryInto<usize> + TryFrom<usize>,
+{
+    /// Converts this position into an index into a flat, row-major array of tiles that is `width` tiles wide
+    ///
+    /// The tile at the origin has index 0, and indexes increase along the x-axis first, then the y-axis.
+    /// Returns `None` if either coordinate is negative, if `x` does not lie within the row,
+    /// or if the index would overflow a [`usize`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::discrete::OrthogonalGrid;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let tiles = vec!['a', 'b', 'c', 'd', 'e', 'f'];
+    /// let width = 3;
+    ///
+    /// let position = Position::<OrthogonalGrid>::new(1, 1);
+    /// assert_eq!(position.to_index(width), Some(4));
+    /// assert_eq!(tiles[position.to_index(width).unwrap()], 'e');
+    ///
+    /// assert_eq!(Position::<OrthogonalGrid>::new(3, 0).to_index(width), None);
+    /// assert_eq!(Position::<OrthogonalGrid>::new(0, -1).to_index(width), None);
+    /// ```
+    #[must_use]
+    pub fn to_index(self, width: usize) -> Option<usize> {
+        let x: C::Data = self.x.into();
+        let y: C::Data = self.y.into();
+        let x: usize = x.try_into().ok()?;
+        let y: usize = y.try_into().ok()?;
+
+        if x >= width {
+            return None;
+        }
+
+        y.checked_mul(width)?.checked_add(x)
+    }
+
+    /// Converts an index into a flat, row-major array of tiles that is `width` tiles wide back into a position
+    ///
+    /// This is the inverse of [`Position::to_index`].
+    /// Returns `None` if `width` is zero, or if the position cannot be represented by `C`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::discrete::OrthogonalGrid;
+    /// use leafwing_2d::position::Position;
+    ///
+    /// let position = Position::<OrthogonalGrid>::from_index(4, 3);
+    /// assert_eq!(position, Some(Position::new(1, 1)));
+    /// ```
+    #[must_use]
+    pub fn from_index(index: usize, width: usize) -> Option<Position<C>> {
+        if width == 0 {
+            return None;
+        }
+
+        let x: C::Data = (index % width).try_into().ok()?;
+        let y: C::Data = (index / width).try_into().ok()?;
+
+        Some(Position {
+            x: x.into(),
+            y: y.into(),
+        })
+    }
+}
+
 /// [`DiscreteCoordinate`] primitive for a square grid, where each cell has four neighbors
 ///
 /// Neighboring tiles must touch on their faces
@@ -1,6 +1,9 @@
 //! Errors that may occur when working with 2D coordinates
 
-use derive_more::{Display, Error};
+use alloc::string::String;
+use derive_more::Display;
+#[cfg(feature = "std")]
+use derive_more::Error;
 
 /// The supplied vector-like struct was too close to zero to be converted into a rotation-like type
 ///
@@ -9,5 +12,44 @@ use derive_more::{Display, Error};
 /// such as a [`Vec2`].
 ///
 /// In almost all cases, the correct way to handle this error is to simply not change the rotation.
-#[derive(Debug, Clone, Copy, Error, Display, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
 pub struct NearlySingularConversion;
+
+/// A floating point value could not be converted into a [`Coordinate`](crate::coordinate::Coordinate) without losing data
+///
+/// This error is produced by checked conversions like [`Coordinate::try_from_f32`](crate::coordinate::Coordinate::try_from_f32)
+/// and [`Position::try_from_transform`](crate::position::Position::try_from_transform).
+/// The unchecked conversions used when synchronizing with a [`Transform`](bevy_transform::components::Transform)
+/// saturate or produce garbage values in these cases instead.
+#[derive(Debug, Clone, Copy, Display, PartialEq)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub enum CoordinateConversionError {
+    /// The value was infinite or `NaN`
+    #[display(fmt = "{} is not a finite number", value)]
+    NonFinite {
+        /// The value that could not be converted
+        value: f32,
+    },
+    /// The value was outside of the range between [`Coordinate::MIN`](crate::coordinate::Coordinate::MIN)
+    /// and [`Coordinate::MAX`](crate::coordinate::Coordinate::MAX)
+    #[display(fmt = "{} is outside of the representable range", value)]
+    OutOfRange {
+        /// The value that could not be converted
+        value: f32,
+    },
+}
+
+/// A string could not be parsed as a [`Rotation`](crate::orientation::Rotation) or [`Direction`](crate::orientation::Direction)
+///
+/// This error is produced by the [`FromStr`](core::str::FromStr) implementations of these types.
+#[derive(Debug, Clone, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
+#[display(
+    fmt = "{:?} is not a compass point or an angle in degrees or radians",
+    input
+)]
+pub struct ParseOrientationError {
+    /// The string that could not be parsed
+    pub input: String,
+}
@@ -0,0 +1,150 @@
+//! Events for reacting when the facing of an entity moves into a new partition
+
+use crate::orientation::Rotation;
+use crate::partitioning::{CardinalQuadrant, DirectionParitioning};
+use crate::plugin::TwoDSystem;
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+/// Sent when the [`Rotation`] of an entity with a [`TrackFacing<P>`] component snaps to a different partition of `P`
+///
+/// This is useful for gameplay that only cares about coarse facing, such as flipping sprites
+/// or playing directional footstep sounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FacingChanged<P: DirectionParitioning = CardinalQuadrant> {
+    /// The entity whose facing changed
+    pub entity: Entity,
+    /// The partition that the entity was previously facing
+    pub from: P,
+    /// The partition that the entity is now facing
+    pub to: P,
+}
+
+/// Tracks which partition of `P` this entity's [`Rotation`] snaps to, sending [`FacingChanged<P>`] events when it changes
+///
+/// The tracking is performed by [`send_facing_changed::<P>`], which is added by [`FacingEventsPlugin<P>`].
+/// No event is sent when the partition is first recorded.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use bevy::ecs::event::Events;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::facing::{FacingChanged, FacingEventsPlugin, TrackFacing};
+///
+/// let mut app = App::new();
+/// app.add_plugin(TwoDPlugin {
+///     kinematics: false,
+///     ..Default::default()
+/// })
+/// .add_plugin(FacingEventsPlugin::<CardinalQuadrant>::default());
+///
+/// let entity = app
+///     .world
+///     .spawn()
+///     .insert_bundle(TwoDBundle::<F32>::default())
+///     .insert(TrackFacing::<CardinalQuadrant>::default())
+///     .id();
+/// app.update();
+///
+/// *app.world.get_mut::<Rotation>(entity).unwrap() = Rotation::SOUTH;
+/// app.update();
+///
+/// let events = app.world.resource::<Events<FacingChanged<CardinalQuadrant>>>();
+/// let event = events.get_reader().iter(events).next().copied().unwrap();
+/// assert_eq!(event.from, CardinalQuadrant::North);
+/// assert_eq!(event.to, CardinalQuadrant::South);
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackFacing<P: DirectionParitioning + Send + Sync + 'static = CardinalQuadrant> {
+    current: Option<P>,
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static> Default for TrackFacing<P> {
+    fn default() -> Self {
+        Self { current: None }
+    }
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static> TrackFacing<P> {
+    /// The partition that this entity was facing when it was last checked
+    ///
+    /// This is `None` until [`send_facing_changed::<P>`] has run for this entity.
+    #[inline]
+    #[must_use]
+    pub fn current(&self) -> Option<P> {
+        self.current
+    }
+}
+
+/// Sends a [`FacingChanged<P>`] event for each entity whose [`Rotation`] now snaps to a different partition of `P`
+///
+/// Only entities with a [`TrackFacing<P>`] component are checked.
+pub fn send_facing_changed<P: DirectionParitioning + PartialEq + Send + Sync + 'static>(
+    mut query: Query<
+        (Entity, &Rotation, &mut TrackFacing<P>),
+        Or<(Changed<Rotation>, Added<TrackFacing<P>>)>,
+    >,
+    mut events: EventWriter<FacingChanged<P>>,
+) {
+    for (entity, &rotation, mut track_facing) in query.iter_mut() {
+        let snapped = P::snap(rotation);
+
+        match track_facing.current {
+            Some(previous) if previous == snapped => (),
+            Some(previous) => {
+                track_facing.current = Some(snapped);
+                events.send(FacingChanged {
+                    entity,
+                    from: previous,
+                    to: snapped,
+                });
+            }
+            None => track_facing.current = Some(snapped),
+        }
+    }
+}
+
+/// Adds the [`FacingChanged<P>`] event, and [`send_facing_changed::<P>`] to send it
+///
+/// Add one copy of this plugin for each partitioning type that you use with [`TrackFacing`].
+/// The system runs after [`TwoDSystem::SyncTransform`], so that it sees the final [`Rotation`] for the frame.
+/// The `stage` should match the stage used by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+#[derive(Debug)]
+pub struct FacingEventsPlugin<
+    P: DirectionParitioning + PartialEq + Send + Sync + 'static = CardinalQuadrant,
+    UserStage: StageLabel = CoreStage,
+> {
+    /// Which stage should this system run in?
+    ///
+    /// Default: [`CoreStage::PostUpdate`]
+    pub stage: UserStage,
+    /// What [`DirectionParitioning`] should facing be tracked with?
+    pub partitioning: PhantomData<P>,
+}
+
+impl<P: DirectionParitioning + PartialEq + Send + Sync + 'static> Default
+    for FacingEventsPlugin<P, CoreStage>
+{
+    fn default() -> Self {
+        Self {
+            stage: CoreStage::PostUpdate,
+            partitioning: PhantomData,
+        }
+    }
+}
+
+impl<
+        P: DirectionParitioning + PartialEq + Send + Sync + 'static,
+        UserStage: StageLabel + Clone,
+    > Plugin for FacingEventsPlugin<P, UserStage>
+{
+    fn build(&self, app: &mut App) {
+        app.add_event::<FacingChanged<P>>().add_system_to_stage(
+            self.stage.clone(),
+            send_facing_changed::<P>.after(TwoDSystem::SyncTransform),
+        );
+    }
+}
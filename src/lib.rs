@@ -2,31 +2,119 @@
 #![forbid(unsafe_code)]
 #![warn(clippy::doc_markdown)]
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Without the `std` feature, the `libm` feature must be enabled to provide the orientation math.");
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod bounding;
+#[cfg(feature = "std")]
 pub mod bundles;
+#[cfg(feature = "std")]
+pub mod compass;
+#[cfg(feature = "std")]
 pub mod continuous;
+#[cfg(feature = "std")]
 pub mod coordinate;
+#[cfg(feature = "debug_lines")]
+pub mod debug;
+#[cfg(feature = "std")]
 pub mod discrete;
 pub mod errors;
+#[cfg(feature = "std")]
+pub mod facing;
+#[cfg(feature = "std")]
 pub mod kinematics;
 pub mod orientation;
+#[cfg(feature = "std")]
 pub mod partitioning;
+#[cfg(feature = "std")]
 pub mod plugin;
+#[cfg(feature = "std")]
 pub mod position;
+#[cfg(feature = "std")]
 pub mod scale;
+#[cfg(feature = "std")]
+pub mod snapping;
+#[cfg(feature = "std")]
+pub mod spatial;
+#[cfg(feature = "std")]
+pub mod targeting;
+#[cfg(feature = "std")]
+pub mod transform;
+
+#[cfg(feature = "approx")]
+mod approx_impls;
+#[cfg(feature = "rapier")]
+mod rapier_impls;
+
+/// Re-exports used by the code generated in `leafwing_2d_macros`
+#[doc(hidden)]
+pub mod __private {
+    pub use glam::Vec2;
+}
 
 /// The most commonly useful bits of the library
+///
+/// This is designed to be glob-imported alongside `bevy::prelude::*` without any name collisions.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugin(TwoDPlugin {
+///     kinematics: false,
+///     ..Default::default()
+/// });
+///
+/// let entity = app
+///     .world
+///     .spawn()
+///     .insert_bundle(TwoDBundle::<F32> {
+///         position: Position::new(1.0, 2.0),
+///         rotation: Rotation::EAST,
+///         direction: Direction::EAST,
+///         ..Default::default()
+///     })
+///     .insert(Transform::default())
+///     .id();
+/// app.update();
+///
+/// let rotation = *app.world.get::<Rotation>(entity).unwrap();
+/// assert_eq!(rotation.rotation_direction(Rotation::NORTH), RotationDirection::CounterClockwise);
+///
+/// assert_eq!(CardinalQuadrant::snap(rotation), CardinalQuadrant::East);
+/// assert_eq!(OffsetQuadrant::snap(Rotation::from_degrees(60.0)), OffsetQuadrant::NorthEast);
+/// assert_eq!(CardinalOctant::snap(rotation), CardinalOctant::East);
+/// assert_eq!(CardinalSextant::snap(Rotation::NORTH), CardinalSextant::North);
+/// assert_eq!(OffsetSextant::snap(Rotation::EAST), OffsetSextant::East);
+/// ```
+#[cfg(feature = "std")]
 pub mod prelude {
     pub use crate::bounding::{AxisAlignedBoundingBox, BoundingRegion};
     pub use crate::bundles::TwoDBundle;
-    pub use crate::continuous::F32;
+    pub use crate::continuous::{F32, F64};
     pub use crate::coordinate::Coordinate;
     pub use crate::discrete::DiscreteCoordinate;
+    pub use crate::facing::{FacingChanged, FacingEventsPlugin, TrackFacing};
     pub use crate::kinematics::{
         Acceleration, AngularAcceleration, AngularVelocity, Kinematic, Velocity,
     };
-    pub use crate::orientation::{Direction, Orientation, OrientationPositionInterop, Rotation};
-    pub use crate::plugin::TwoDPlugin;
+    pub use crate::orientation::{
+        AngleUnit, Direction, Orientation, OrientationPositionInterop, Rotation, RotationDirection,
+    };
+    pub use crate::partitioning::{
+        CardinalHexadecant, CardinalOctant, CardinalQuadrant, CardinalSextant,
+        DirectionParitioning, OffsetQuadrant, OffsetSextant,
+    };
+    pub use crate::plugin::{SyncPolicy, TwoDPlugin, TwoDSystem};
     pub use crate::position::{Position, Positionlike};
+    pub use crate::snapping::{SnapTo, SnapToPlugin};
+    pub use crate::spatial::SpatialHash;
+    pub use crate::targeting::FaceTarget;
 }
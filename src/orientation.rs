@@ -1,16 +1,25 @@
 //! Direction and rotation for spinning around in 2 dimensions
 
 pub use direction::Direction;
+#[cfg(feature = "std")]
 pub use orientation_position_trait::OrientationPositionInterop;
 pub use orientation_trait::Orientation;
-pub use rotation::Rotation;
+pub use rotation::{AngleUnit, Rotation};
 pub use rotation_direction::RotationDirection;
 
+/// The magnitude below which a vector is considered too close to zero to have a meaningful angle
+///
+/// This threshold is shared by every conversion from a [`Vec2`](glam::Vec2) into a [`Rotation`] or [`Direction`],
+/// so that they always agree on which vectors are singular.
+/// Use [`Rotation::from_vec2_with_epsilon`] or [`Direction::from_vec2_with_epsilon`] to supply a different threshold.
+pub const SINGULARITY_EPSILON: f32 = f32::EPSILON;
+
 mod orientation_trait {
     use super::{Direction, Rotation, RotationDirection};
-    use bevy_math::Quat;
+    #[cfg(feature = "std")]
     use bevy_transform::components::{GlobalTransform, Transform};
     use core::fmt::Debug;
+    use glam::Quat;
 
     /// A type that can represent a orientation in 2D space
     pub trait Orientation: Sized + Debug + From<Rotation> + Into<Rotation> + Copy {
@@ -115,6 +124,9 @@ mod orientation_trait {
 
         /// Rotates `self` towards `target_orientation` by up to `max_rotation`
         ///
+        /// Returns `true` if `self` reached `target_orientation` during this call,
+        /// and `false` if it was limited by `max_rotation`.
+        ///
         /// # Example
         /// ```rust
         /// use leafwing_2d::orientation::{Rotation, Orientation};
@@ -122,30 +134,42 @@ mod orientation_trait {
         /// let mut rotation = Rotation::SOUTH;
         ///
         /// // Without a `max_rotation`, the orientation snaps
-        /// rotation.rotate_towards(Rotation::WEST, None);
+        /// assert!(rotation.rotate_towards(Rotation::WEST, None));
         /// assert_eq!(rotation, Rotation::WEST);
         ///
         /// // With a `max_rotation`, we don't get all the way there
-        /// rotation.rotate_towards(Rotation::SOUTH, Some(Rotation::new(450)));
+        /// assert!(!rotation.rotate_towards(Rotation::SOUTH, Some(Rotation::new(450))));
         /// assert_eq!(rotation, Rotation::SOUTHWEST);
+        ///
+        /// // But the next step lands on the target
+        /// assert!(rotation.rotate_towards(Rotation::SOUTH, Some(Rotation::new(450))));
+        /// assert_eq!(rotation, Rotation::SOUTH);
         /// ```
         #[inline]
-        fn rotate_towards(&mut self, target_orientation: Self, max_rotation: Option<Rotation>) {
+        fn rotate_towards(
+            &mut self,
+            target_orientation: Self,
+            max_rotation: Option<Rotation>,
+        ) -> bool {
             if let Some(max_rotation) = max_rotation {
                 if self.distance(target_orientation) <= max_rotation {
                     *self = target_orientation;
+                    true
                 } else {
-                    let delta_rotation = match self.rotation_direction(target_orientation) {
-                        RotationDirection::Clockwise => max_rotation,
-                        RotationDirection::CounterClockwise => -max_rotation,
-                    };
                     let current_rotation: Rotation = (*self).into();
+                    let target_rotation: Rotation = target_orientation.into();
+                    let delta_rotation = Rotation::clamp_to_max_step(
+                        target_rotation - current_rotation,
+                        max_rotation,
+                    );
                     let new_rotation: Rotation = current_rotation + delta_rotation;
 
                     *self = new_rotation.into();
+                    false
                 }
             } else {
                 *self = target_orientation;
+                true
             }
         }
     }
@@ -177,6 +201,55 @@ mod orientation_trait {
             let other_rotation: Rotation = other.into();
             self_rotation.distance(other_rotation)
         }
+
+        /// Steers the unit vector of `self` towards `target_orientation` by up to `max_rotation`
+        ///
+        /// The unit vector is rotated directly, rather than being rounded to the nearest [`Rotation`] first,
+        /// and remains normalized.
+        /// If either `self` or `target_orientation` is [`Direction::NEUTRAL`], there is no way to turn,
+        /// so `self` is left unchanged and `false` is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+        ///
+        /// let mut direction = Direction::NORTH;
+        ///
+        /// assert!(!direction.rotate_towards(Direction::EAST, Some(Rotation::from_degrees(30.0))));
+        /// direction.assert_approx_eq(Rotation::from_degrees(30.0));
+        ///
+        /// let mut neutral = Direction::NEUTRAL;
+        /// assert!(!neutral.rotate_towards(Direction::EAST, None));
+        /// assert_eq!(neutral, Direction::NEUTRAL);
+        /// ```
+        fn rotate_towards(
+            &mut self,
+            target_orientation: Direction,
+            max_rotation: Option<Rotation>,
+        ) -> bool {
+            if self.is_neutral() || target_orientation.is_neutral() {
+                return false;
+            }
+
+            match max_rotation {
+                Some(max_rotation) if self.distance(target_orientation) > max_rotation => {
+                    let current_rotation: Rotation = (*self).into();
+                    let target_rotation: Rotation = target_orientation.into();
+                    let delta_rotation = Rotation::clamp_to_max_step(
+                        target_rotation - current_rotation,
+                        max_rotation,
+                    );
+
+                    let rotated = delta_rotation.to_mat2() * self.unit_vector();
+                    *self = Direction::new(rotated);
+                    false
+                }
+                _ => {
+                    *self = target_orientation;
+                    true
+                }
+            }
+        }
     }
 
     impl Orientation for Quat {
@@ -187,6 +260,7 @@ mod orientation_trait {
         }
     }
 
+    #[cfg(feature = "std")]
     impl Orientation for Transform {
         fn distance(&self, other: Transform) -> Rotation {
             let self_rotation: Rotation = (*self).into();
@@ -195,6 +269,7 @@ mod orientation_trait {
         }
     }
 
+    #[cfg(feature = "std")]
     impl Orientation for GlobalTransform {
         fn distance(&self, other: GlobalTransform) -> Rotation {
             let self_rotation: Rotation = (*self).into();
@@ -204,6 +279,7 @@ mod orientation_trait {
     }
 }
 
+#[cfg(feature = "std")]
 mod orientation_position_trait {
     use crate::coordinate::Coordinate;
     use crate::errors::NearlySingularConversion;
@@ -244,6 +320,9 @@ mod orientation_position_trait {
 
         /// Rotates `self` towards `target_position` by up to `max_rotation`
         ///
+        /// Returns `true` if `self` now faces `target_position`.
+        /// If the two positions are the same, `self` is unchanged and `false` is returned.
+        ///
         /// # Example
         /// ```rust
         /// use leafwing_2d::orientation::{OrientationPositionInterop, Orientation, Direction, Rotation};
@@ -270,11 +349,13 @@ mod orientation_position_trait {
             current_position: Position<C>,
             target_position: Position<C>,
             max_rotation: Option<Rotation>,
-        ) {
+        ) -> bool {
             if let Ok(target_orientation) =
                 Self::orientation_between_positions(current_position, target_position)
             {
-                self.rotate_towards(target_orientation, max_rotation);
+                self.rotate_towards(target_orientation, max_rotation)
+            } else {
+                false
             }
         }
     }
@@ -341,17 +422,33 @@ mod rotation_direction {
 }
 
 mod rotation {
+    use super::float_math;
+    use super::{Orientation, RotationDirection, SINGULARITY_EPSILON};
     use crate::errors::NearlySingularConversion;
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
     use bevy_ecs::prelude::Component;
-    use bevy_math::Vec2;
+    use core::cmp::Ordering;
+    use core::fmt::{Display, Formatter};
     use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
-    use derive_more::Display;
+    use glam::{Mat2, Vec2};
 
     /// A discretized 2-dimensional rotation
     ///
     /// Internally, these are stored in normalized tenths of a degree, and so can be cleanly added and reversed
     /// without accumulating error.
     ///
+    /// Multiplying or dividing a [`Rotation`] by an `f32` never panics:
+    /// if the result is not finite (such as when dividing by zero), [`Rotation::NORTH`] is returned.
+    ///
+    /// # Ordering
+    ///
+    /// The [`PartialOrd`] implementation compares the stored angles as plain numbers, measured clockwise from north.
+    /// This is a linear order with a seam at north, rather than an angular one:
+    /// `Rotation::from_degrees(359.0)` is greater than `Rotation::from_degrees(1.0)`, even though they are only 2 degrees apart.
+    /// Use [`Orientation::distance`] to compare how close rotations are,
+    /// or [`Rotation::cyclic_cmp`] to order rotations by how far clockwise they are from a chosen origin.
+    ///
     /// # Example
     /// ```rust
     /// use leafwing_2d::orientation::{Rotation, Direction, Orientation};
@@ -374,7 +471,8 @@ mod rotation {
     ///
     /// Direction::from(nine_o_clock).assert_approx_eq(Direction::WEST);
     /// ```
-    #[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Default, Display)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Default)]
+    #[cfg_attr(feature = "std", derive(Component))]
     pub struct Rotation {
         /// Tenths of a degree, measured clockwise from midnight (x=0, y=1)
         ///
@@ -404,238 +502,1652 @@ mod rotation {
         pub const fn deci_degrees(&self) -> u16 {
             self.deci_degrees
         }
-    }
-
-    // Constants
-    impl Rotation {
-        /// The number of deci-degrees that make up a full circle
-        pub const FULL_CIRCLE: u16 = 3600;
-
-        /// The direction that points straight up
-        pub const NORTH: Rotation = Rotation { deci_degrees: 0 };
-
-        /// The direction that points straight right
-        pub const EAST: Rotation = Rotation { deci_degrees: 900 };
-        /// The direction that points straight down
-        pub const SOUTH: Rotation = Rotation { deci_degrees: 1800 };
-        /// The direction that points straight left
-        pub const WEST: Rotation = Rotation { deci_degrees: 2700 };
 
-        /// The direction that points halfway between up and right
-        pub const NORTHEAST: Rotation = Rotation { deci_degrees: 450 };
-        /// The direction that points halfway between down and right
-        pub const SOUTHEAST: Rotation = Rotation { deci_degrees: 1350 };
-        /// The direction that points halfway between down and left
-        pub const SOUTHWEST: Rotation = Rotation { deci_degrees: 2250 };
-        /// The direction that points halfway between left and up
-        pub const NORTHWEST: Rotation = Rotation { deci_degrees: 3150 };
-    }
+        /// Creates a new [`Rotation`] from a signed number of tenths of a degree
+        ///
+        /// Positive values are measured clockwise from midnight, and negative values counterclockwise,
+        /// so `-900` is [`Rotation::WEST`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_signed_deci_degrees(-900), Rotation::WEST);
+        /// assert_eq!(Rotation::from_signed_deci_degrees(4500), Rotation::EAST);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub const fn from_signed_deci_degrees(deci_degrees: i16) -> Rotation {
+            Rotation {
+                deci_degrees: (deci_degrees as i32).rem_euclid(Rotation::FULL_CIRCLE as i32) as u16,
+            }
+        }
 
-    // Conversion methods
-    impl Rotation {
-        /// Constructs a [`Rotation`](crate::orientation::Direction) from a [`Vec2`](glam::Vec2)
+        /// Returns the shortest signed angle from north, in tenths of a degree
         ///
-        /// If both x and y are nearly 0 (the magnitude is less than [`EPSILON`](f32::EPSILON)),
-        /// [`Err(NearlySingularConversion)`] will be returned instead.
+        /// Positive values are clockwise, and negative values are counterclockwise.
+        /// The result lies within `(-1800, 1800]`: [`Rotation::SOUTH`] is `1800`.
         ///
         /// # Example
         /// ```rust
-        /// use bevy_math::Vec2;
         /// use leafwing_2d::orientation::Rotation;
         ///
-        /// assert_eq!(Rotation::from_xy(Vec2::new(0.0, 1.0)), Ok(Rotation::NORTH));
+        /// assert_eq!(Rotation::EAST.signed_deci_degrees(), 900);
+        /// assert_eq!(Rotation::WEST.signed_deci_degrees(), -900);
+        /// assert_eq!(Rotation::SOUTH.signed_deci_degrees(), 1800);
         /// ```
         #[inline]
-        pub fn from_vec2(vec: Vec2) -> Result<Rotation, NearlySingularConversion> {
-            if vec.length_squared() < f32::EPSILON * f32::EPSILON {
-                Err(NearlySingularConversion)
+        #[must_use]
+        pub const fn signed_deci_degrees(&self) -> i16 {
+            if self.deci_degrees <= Rotation::FULL_CIRCLE / 2 {
+                self.deci_degrees as i16
             } else {
-                let radians = f32::atan2(vec.x, vec.y);
-                Ok(Rotation::from_radians(radians))
+                self.deci_degrees as i16 - Rotation::FULL_CIRCLE as i16
             }
         }
 
-        /// Converts this direction into a [`Vec2`](glam::Vec2) with magnitude 1
+        /// Is `self` within the arc that extends `half_width` to either side of `center`?
+        ///
+        /// Rotations that lie exactly on the edge of the arc are considered to be within it.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let half_width = Rotation::from_degrees(30.0);
+        ///
+        /// assert!(Rotation::from_degrees(100.0).is_within_arc(Rotation::EAST, half_width));
+        /// assert!(Rotation::from_degrees(120.0).is_within_arc(Rotation::EAST, half_width));
+        /// assert!(!Rotation::from_degrees(121.0).is_within_arc(Rotation::EAST, half_width));
+        ///
+        /// // Arcs wrap around midnight
+        /// assert!(Rotation::from_degrees(350.0).is_within_arc(Rotation::from_degrees(10.0), half_width));
+        /// assert!(!Rotation::from_degrees(330.0).is_within_arc(Rotation::from_degrees(10.0), half_width));
+        /// ```
         #[inline]
         #[must_use]
-        pub fn into_vec2(self) -> Vec2 {
-            let radians = self.into_radians();
-            Vec2::new(radians.sin(), radians.cos())
+        pub fn is_within_arc(self, center: Rotation, half_width: Rotation) -> bool {
+            self.distance(center) <= half_width
         }
 
-        /// Construct a [`Direction`](crate::orientation::Direction) from radians, measured clockwise from midnight
+        /// The size of the long arc between `self` and `other`
+        ///
+        /// This is the full circle minus [`Orientation::distance`], and is the angle swept when turning the long way around:
+        /// in the opposite direction to [`Orientation::rotation_direction`].
+        /// When `self` and `other` are exactly opposite, both arcs are half a turn.
+        ///
+        /// A full turn cannot be represented by a [`Rotation`],
+        /// so identical rotations have a reflex distance of [`Rotation::NORTH`] (zero).
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Orientation, Rotation};
+        ///
+        /// assert_eq!(Rotation::NORTH.distance(Rotation::EAST), Rotation::from_degrees(90.0));
+        /// assert_eq!(Rotation::NORTH.reflex_distance(Rotation::EAST), Rotation::from_degrees(270.0));
+        /// assert_eq!(Rotation::NORTH.reflex_distance(Rotation::SOUTH), Rotation::SOUTH);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn reflex_distance(self, other: Rotation) -> Rotation {
+            Rotation::new(Rotation::FULL_CIRCLE - self.distance(other).deci_degrees)
+        }
+
+        /// Does the shortest path from `self` to `target` pass through the arc that extends `forbidden_half_width` to either side of `forbidden_center`?
+        ///
+        /// The shortest path is the one chosen by [`Orientation::rotation_direction`], and includes both `self` and `target`.
+        /// As with [`Rotation::is_within_arc`], the edges of the forbidden arc are considered to be within it,
+        /// so a path that starts or ends inside the arc (or just touches its edge) crosses it.
+        ///
+        /// This is useful for turrets with a dead zone: if the short way around is blocked, turn the long way instead.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// // The turret cannot point at its own chassis, behind it
+        /// let dead_zone = Rotation::SOUTH;
+        /// let half_width = Rotation::from_degrees(20.0);
+        ///
+        /// // The short way from east to west passes through south
+        /// let from_east = Rotation::from_degrees(100.0);
+        /// let to_west = Rotation::from_degrees(260.0);
+        /// assert!(from_east.path_crosses(to_west, dead_zone, half_width));
+        ///
+        /// // The short way from northeast to northwest passes through north instead
+        /// assert!(!Rotation::NORTHEAST.path_crosses(Rotation::NORTHWEST, dead_zone, half_width));
+        /// ```
         #[must_use]
+        pub fn path_crosses(
+            self,
+            target: Rotation,
+            forbidden_center: Rotation,
+            forbidden_half_width: Rotation,
+        ) -> bool {
+            let full_circle = Rotation::FULL_CIRCLE as u32;
+            let forbidden_width = 2 * forbidden_half_width.deci_degrees as u32;
+
+            if forbidden_width >= full_circle {
+                return true;
+            }
+
+            // Both the path and the forbidden arc are described as a start point and a clockwise length
+            let (path_start, path_length) = match self.rotation_direction(target) {
+                RotationDirection::Clockwise => (self, target - self),
+                RotationDirection::CounterClockwise => (target, self - target),
+            };
+            let forbidden_start = forbidden_center - forbidden_half_width;
+
+            // Two clockwise arcs overlap if and only if one of them starts within the other
+            (forbidden_start - path_start).deci_degrees <= path_length.deci_degrees
+                || (path_start - forbidden_start).deci_degrees as u32 <= forbidden_width
+        }
+
+        /// Produces `count` evenly spaced rotations, arranged symmetrically around `center`
+        ///
+        /// Adjacent rotations are separated by `spacing`, and are returned in clockwise order.
+        /// When `count` is odd, `center` is included; when it is even, `center` lies halfway between the two middle rotations.
+        /// A `count` of 0 produces no rotations.
+        ///
+        /// This is useful for spread patterns, such as the pellets of a shotgun blast.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let spacing = Rotation::from_degrees(10.0);
+        /// let spread: Vec<Rotation> = Rotation::fan(Rotation::NORTH, 3, spacing).collect();
+        ///
+        /// assert_eq!(spread, vec![Rotation::from_degrees(350.0), Rotation::NORTH, Rotation::from_degrees(10.0)]);
+        /// ```
         #[inline]
-        pub fn from_radians(radians: impl Into<f32>) -> Rotation {
-            use std::f32::consts::TAU;
+        pub fn fan(
+            center: Rotation,
+            count: usize,
+            spacing: Rotation,
+        ) -> impl Iterator<Item = Rotation> {
+            let center = center.deci_degrees as i64;
+            let spacing = spacing.deci_degrees as i64;
+            let count = count as i64;
+
+            (0..count).map(move |index| {
+                // The offset is doubled so that it is exact when `count` is even
+                let doubled_offset = (2 * index - (count - 1)) * spacing;
+                let deci_degrees =
+                    (center + doubled_offset / 2).rem_euclid(Rotation::FULL_CIRCLE as i64);
 
-            let normalized_radians: f32 = radians.into().rem_euclid(TAU);
+                Rotation {
+                    deci_degrees: deci_degrees as u16,
+                }
+            })
+        }
 
-            Rotation {
-                deci_degrees: (normalized_radians * 3600. / TAU) as u16,
-            }
+        /// Produces evenly spaced rotations from `from` to `to`, going the short way around
+        ///
+        /// Both endpoints are included, splitting the arc into `steps` equal pieces and so returning `steps + 1` rotations.
+        /// A `steps` of 0 produces only `from`.
+        /// When `from` and `to` are exactly opposite, the arc is traced clockwise, matching [`Orientation::rotation_direction`].
+        ///
+        /// Unlike [`Rotation::fan`], which spreads rotations around a center, this is defined by the endpoints of the arc,
+        /// and is useful for drawing arcs such as cone-of-fire indicators.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let arc: Vec<Rotation> = Rotation::subdivide(Rotation::EAST, Rotation::NORTH, 2).collect();
+        ///
+        /// assert_eq!(arc, vec![Rotation::EAST, Rotation::NORTHEAST, Rotation::NORTH]);
+        /// ```
+        #[inline]
+        pub fn subdivide(
+            from: Rotation,
+            to: Rotation,
+            steps: usize,
+        ) -> impl Iterator<Item = Rotation> {
+            let start = from.deci_degrees as i64;
+            let delta = (to - from).signed_deci_degrees() as i64;
+            // Avoids dividing by zero: with no steps, only the first rotation is produced
+            let divisor = steps.max(1) as i64;
+
+            (0..=steps as i64).map(move |index| {
+                // Rounds to the nearest tenth of a degree, so that the spacing is as even as possible
+                let offset = (2 * delta * index + divisor).div_euclid(2 * divisor);
+                let deci_degrees = (start + offset).rem_euclid(Rotation::FULL_CIRCLE as i64);
+
+                Rotation {
+                    deci_degrees: deci_degrees as u16,
+                }
+            })
         }
 
-        /// Converts this direction into radians, measured clockwise from midnight
+        /// Returns the rotation facing the opposite way, half a turn away from `self`
+        ///
+        /// This is equivalent to `self + Rotation::SOUTH`.
+        /// Note that this is not the same as [`Neg`], which reverses the direction of turning
+        /// (reflecting across the north-south axis): `-Rotation::EAST` is [`Rotation::WEST`],
+        /// but `-Rotation::NORTH` is still [`Rotation::NORTH`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::NORTH.opposite(), Rotation::SOUTH);
+        /// assert_eq!(Rotation::NORTHEAST.opposite(), Rotation::SOUTHWEST);
+        /// assert_eq!(-Rotation::NORTH, Rotation::NORTH);
+        /// ```
         #[inline]
         #[must_use]
-        pub fn into_radians(self) -> f32 {
-            self.deci_degrees as f32 * std::f32::consts::TAU / 3600.
+        pub fn opposite(self) -> Rotation {
+            self + Rotation::SOUTH
         }
 
-        /// Construct a [`Direction`](crate::orientation::Direction) from degrees, measured clockwise from midnight
-        #[must_use]
+        /// Reflects `self` across the line that passes through `axis`
+        ///
+        /// Because `axis` is treated as a line, `axis` and its opposite produce the same reflection.
+        /// Unlike [`Neg`], which reverses the direction of turning,
+        /// this keeps rotations that lie on the axis fixed.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// // Mirroring across the vertical axis swaps left and right
+        /// assert_eq!(Rotation::EAST.mirror_across(Rotation::NORTH), Rotation::WEST);
+        /// assert_eq!(Rotation::SOUTH.mirror_across(Rotation::NORTH), Rotation::SOUTH);
+        ///
+        /// // Mirroring across the horizontal axis swaps up and down
+        /// assert_eq!(Rotation::NORTHEAST.mirror_across(Rotation::EAST), Rotation::SOUTHEAST);
+        /// ```
         #[inline]
-        pub fn from_degrees(degrees: impl Into<f32>) -> Rotation {
-            let normalized_degrees: f32 = degrees.into().rem_euclid(360.0);
+        #[must_use]
+        pub fn mirror_across(self, axis: Rotation) -> Rotation {
+            let deci_degrees = 2 * axis.deci_degrees as i32 - self.deci_degrees as i32;
 
             Rotation {
-                deci_degrees: (normalized_degrees * 10.0) as u16,
+                deci_degrees: deci_degrees.rem_euclid(Rotation::FULL_CIRCLE as i32) as u16,
             }
         }
 
-        /// Converts this direction into degrees, measured clockwise from midnight
+        /// Finds the rotation halfway between `self` and `other`, along the shorter arc between them
+        ///
+        /// Unlike averaging the two angles, this correctly handles arcs that cross north:
+        /// the bisector of 350° and 10° is 0°, not 180°.
+        /// If the arc contains an odd number of deci-degrees, the result is rounded towards `self`.
+        /// If `self` and `other` are exactly opposite, the clockwise arc from `self` is used.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::NORTH.bisect(Rotation::EAST), Rotation::NORTHEAST);
+        /// assert_eq!(Rotation::new(3500).bisect(Rotation::new(100)), Rotation::NORTH);
+        /// ```
         #[inline]
         #[must_use]
-        pub fn into_degrees(self) -> f32 {
-            self.deci_degrees as f32 / 10.
-        }
-    }
-
-    impl Add for Rotation {
-        type Output = Rotation;
-        fn add(self, rhs: Self) -> Rotation {
-            Rotation::new(self.deci_degrees + rhs.deci_degrees)
-        }
-    }
+        pub fn bisect(self, other: Rotation) -> Rotation {
+            let clockwise_arc = (other - self).deci_degrees;
 
-    impl Sub for Rotation {
-        type Output = Rotation;
-        fn sub(self, rhs: Self) -> Rotation {
-            if self.deci_degrees >= rhs.deci_degrees {
-                Rotation::new(self.deci_degrees - rhs.deci_degrees)
+            if clockwise_arc <= Rotation::FULL_CIRCLE / 2 {
+                self + Rotation::new(clockwise_arc / 2)
             } else {
-                Rotation::new(self.deci_degrees + Rotation::FULL_CIRCLE - rhs.deci_degrees)
+                self - Rotation::new((Rotation::FULL_CIRCLE - clockwise_arc) / 2)
             }
         }
-    }
 
-    impl AddAssign for Rotation {
-        fn add_assign(&mut self, rhs: Self) {
-            self.deci_degrees = (self.deci_degrees + rhs.deci_degrees) % Rotation::FULL_CIRCLE;
+        /// Compares `self` and `other` by how far clockwise each one lies from `origin`
+        ///
+        /// `origin` itself is the smallest rotation, and the rotation just counterclockwise of it is the largest.
+        /// When `origin` is [`Rotation::NORTH`], this matches the [`PartialOrd`] implementation.
+        /// This is useful for sorting rotations starting from an arbitrary facing, such as when sweeping a radar.
+        ///
+        /// # Example
+        /// ```rust
+        /// use core::cmp::Ordering;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let just_before_north = Rotation::from_degrees(350.0);
+        /// let just_after_north = Rotation::from_degrees(10.0);
+        ///
+        /// // Linearly, 350 degrees is greater than 10 degrees
+        /// assert!(just_before_north > just_after_north);
+        ///
+        /// // But starting from the west, 350 degrees is reached first
+        /// assert_eq!(just_before_north.cyclic_cmp(just_after_north, Rotation::WEST), Ordering::Less);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn cyclic_cmp(self, other: Rotation, origin: Rotation) -> Ordering {
+            let self_offset = (self - origin).deci_degrees;
+            let other_offset = (other - origin).deci_degrees;
+
+            self_offset.cmp(&other_offset)
         }
-    }
 
-    impl SubAssign for Rotation {
-        fn sub_assign(&mut self, rhs: Self) {
-            // Be sure to avoid overflow when subtracting
-            if self.deci_degrees > rhs.deci_degrees {
-                self.deci_degrees = self.deci_degrees - rhs.deci_degrees;
-            } else {
-                self.deci_degrees = Rotation::FULL_CIRCLE - (rhs.deci_degrees - self.deci_degrees);
-            }
+        /// The clockwise arc lengths between each rotation in `sorted` and the next,
+        /// wrapping from the last rotation back around to the first
+        ///
+        /// The returned vector has one gap per rotation: the gap at index `i` starts at `sorted[i]`.
+        /// When `sorted` is in ascending order, these gaps sum to a full circle.
+        /// Because a full circle cannot be stored in a [`Rotation`],
+        /// the gap following a lone rotation (or between identical rotations) is zero.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let rotations = [Rotation::NORTH, Rotation::EAST, Rotation::SOUTH];
+        ///
+        /// assert_eq!(Rotation::gaps(&rotations), vec![Rotation::from_degrees(90.0), Rotation::from_degrees(90.0), Rotation::from_degrees(180.0)]);
+        /// assert_eq!(Rotation::gaps(&[]), vec![]);
+        /// ```
+        #[must_use]
+        pub fn gaps(sorted: &[Rotation]) -> Vec<Rotation> {
+            sorted
+                .iter()
+                .zip(sorted.iter().cycle().skip(1))
+                .map(|(&current, &next)| next - current)
+                .collect()
         }
-    }
 
-    impl Neg for Rotation {
-        type Output = Rotation;
-        fn neg(self) -> Rotation {
-            Rotation {
-                deci_degrees: Rotation::FULL_CIRCLE - self.deci_degrees,
+        /// Finds the smallest arc that contains every rotation in `rotations`
+        ///
+        /// The arc is returned as `(center, half_width)`: every rotation is at most `half_width` away from `center`.
+        /// The arc may cross north; this is found by locating the largest gap between neighboring rotations,
+        /// and taking the rest of the circle.
+        /// If several gaps are equally large, the gap that crosses north is excluded if it is one of them,
+        /// and the gap that starts at the smallest rotation is excluded otherwise.
+        /// When the arc spans an odd number of deci-degrees, `half_width` is rounded up so that the arc still contains every rotation.
+        ///
+        /// Returns `None` if `rotations` is empty.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let threats = [Rotation::from_degrees(350.0), Rotation::from_degrees(20.0), Rotation::from_degrees(0.0)];
+        /// let (center, half_width) = Rotation::enclosing_arc(&threats).unwrap();
+        ///
+        /// assert_eq!(center, Rotation::from_degrees(5.0));
+        /// assert_eq!(half_width, Rotation::from_degrees(15.0));
+        /// ```
+        #[must_use]
+        pub fn enclosing_arc(rotations: &[Rotation]) -> Option<(Rotation, Rotation)> {
+            let mut deci_degrees: Vec<u16> = rotations
+                .iter()
+                .map(|rotation| rotation.deci_degrees)
+                .collect();
+            deci_degrees.sort_unstable();
+            deci_degrees.dedup();
+
+            let first = *deci_degrees.first()?;
+            let last = *deci_degrees.last()?;
+
+            // The gap that wraps around north, from the largest rotation back to the smallest
+            let mut largest_gap = first + Rotation::FULL_CIRCLE - last;
+            let mut arc_start = first;
+
+            for pair in deci_degrees.windows(2) {
+                let gap = pair[1] - pair[0];
+                if gap > largest_gap {
+                    largest_gap = gap;
+                    arc_start = pair[1];
+                }
             }
-        }
-    }
 
-    impl Mul<f32> for Rotation {
-        type Output = Rotation;
-        fn mul(self, rhs: f32) -> Rotation {
-            Rotation::from_degrees(self.into_degrees() * rhs)
-        }
-    }
+            // Everything outside of the largest gap is covered by the arc
+            let arc_length = Rotation::FULL_CIRCLE - largest_gap;
+            let center = Rotation::new(arc_start) + Rotation::new(arc_length / 2);
+            let half_width = Rotation::new(arc_length - arc_length / 2);
 
-    impl Mul<Rotation> for f32 {
-        type Output = Rotation;
-        fn mul(self, rhs: Rotation) -> Rotation {
-            Rotation::from_degrees(rhs.into_degrees() * self)
+            Some((center, half_width))
         }
-    }
 
-    impl Div<f32> for Rotation {
-        type Output = Rotation;
-        fn div(self, rhs: f32) -> Rotation {
-            Rotation::from_degrees(self.into_degrees() / rhs)
-        }
+        /// Rounds `self` to the nearest multiple of `step`, counting clockwise from north
+        ///
+        /// This is a runtime alternative to a [`DirectionParitioning`](crate::partitioning::DirectionParitioning),
+        /// for when the number of directions is not known in advance.
+        /// Rotations exactly halfway between two multiples are rounded clockwise.
+        ///
+        /// If `step` does not evenly divide the full circle, the last bin (between the largest multiple and north) is narrower than the others:
+        /// rotations in it are rounded to whichever of the largest multiple and [`Rotation::NORTH`] is nearer.
+        /// A `step` of zero leaves `self` unchanged.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let step = Rotation::from_degrees(15.0);
+        ///
+        /// assert_eq!(Rotation::from_degrees(50.0).quantize(step), Rotation::from_degrees(45.0));
+        /// assert_eq!(Rotation::from_degrees(355.0).quantize(step), Rotation::NORTH);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn quantize(self, step: Rotation) -> Rotation {
+            if step.deci_degrees == 0 {
+                return self;
+            }
+
+            let lower = self.deci_degrees / step.deci_degrees * step.deci_degrees;
+            // North bounds the last bin, even if it is not a multiple of `step`
+            let upper = (lower + step.deci_degrees).min(Rotation::FULL_CIRCLE);
+
+            if self.deci_degrees - lower < upper - self.deci_degrees {
+                Rotation::new(lower)
+            } else {
+                Rotation::new(upper)
+            }
+        }
+
+        /// Limits the size of the turn `delta` to at most `max`, preserving its direction
+        ///
+        /// `delta` is interpreted along the short arc: rotations of up to 180 degrees turn clockwise,
+        /// while larger rotations turn counterclockwise (so 350 degrees is a 10 degree counterclockwise turn).
+        /// If this turn is no larger than `max`, `delta` is returned unchanged.
+        /// Otherwise, a turn of exactly `max` in the same direction is returned.
+        ///
+        /// This is the step used by [`Orientation::rotate_towards`], and is useful for limiting turn rates.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let max = Rotation::from_degrees(30.0);
+        ///
+        /// assert_eq!(Rotation::clamp_to_max_step(Rotation::from_degrees(10.0), max), Rotation::from_degrees(10.0));
+        /// assert_eq!(Rotation::clamp_to_max_step(Rotation::from_degrees(90.0), max), max);
+        /// assert_eq!(Rotation::clamp_to_max_step(Rotation::from_degrees(270.0), max), -max);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn clamp_to_max_step(delta: Rotation, max: Rotation) -> Rotation {
+            if delta.distance(Rotation::NORTH) <= max {
+                delta
+            } else if delta.deci_degrees <= Rotation::FULL_CIRCLE / 2 {
+                max
+            } else {
+                -max
+            }
+        }
+
+        /// Adds `rhs` to `self`, stopping at `max` instead of wrapping around the full circle
+        ///
+        /// This treats rotations as a linear range from 0 to 359.9 degrees,
+        /// which is useful when a [`Rotation`] represents a bounded value such as a camera pan.
+        /// The result is never greater than `max`.
+        /// Use the `+` operator instead for ordinary wrapping arithmetic.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let max = Rotation::from_degrees(90.0);
+        ///
+        /// assert_eq!(Rotation::from_degrees(30.0).saturating_add(Rotation::from_degrees(30.0), max), Rotation::from_degrees(60.0));
+        /// assert_eq!(Rotation::from_degrees(80.0).saturating_add(Rotation::from_degrees(30.0), max), max);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn saturating_add(self, rhs: Rotation, max: Rotation) -> Rotation {
+            let sum = self.deci_degrees + rhs.deci_degrees;
+
+            Rotation {
+                deci_degrees: sum.min(max.deci_degrees),
+            }
+        }
+
+        /// Subtracts `rhs` from `self`, stopping at `min` instead of wrapping around the full circle
+        ///
+        /// This treats rotations as a linear range from 0 to 359.9 degrees,
+        /// which is useful when a [`Rotation`] represents a bounded value such as a camera pan.
+        /// The result is never less than `min`.
+        /// Use the `-` operator instead for ordinary wrapping arithmetic.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let min = Rotation::from_degrees(10.0);
+        ///
+        /// assert_eq!(Rotation::from_degrees(50.0).saturating_sub(Rotation::from_degrees(30.0), min), Rotation::from_degrees(20.0));
+        /// assert_eq!(Rotation::from_degrees(20.0).saturating_sub(Rotation::from_degrees(30.0), min), min);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn saturating_sub(self, rhs: Rotation, min: Rotation) -> Rotation {
+            let difference = self.deci_degrees.saturating_sub(rhs.deci_degrees);
+
+            Rotation {
+                deci_degrees: difference.max(min.deci_degrees),
+            }
+        }
+
+        /// Adds `rhs` to `self`, asserting in debug builds that `rhs` is a clockwise step of at most half a turn
+        ///
+        /// Per-frame steps (such as those computed by comparing two frames) should always be small,
+        /// so a larger operand (like a full revolution that silently wraps) usually indicates a logic error.
+        /// In release builds, this is identical to the `+` operator.
+        /// Use [`Rotation::sub_checked`] for counterclockwise steps.
+        ///
+        /// # Panics
+        /// Panics if `rhs` is greater than 180 degrees and debug assertions are enabled.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_degrees(350.0).add_checked(Rotation::from_degrees(20.0)), Rotation::from_degrees(10.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn add_checked(self, rhs: Rotation) -> Rotation {
+            debug_assert!(
+                rhs.deci_degrees <= Rotation::FULL_CIRCLE / 2,
+                "Rotation step {rhs} is larger than half a turn"
+            );
+
+            self + rhs
+        }
+
+        /// Subtracts `rhs` from `self`, asserting in debug builds that `rhs` is a counterclockwise step of at most half a turn
+        ///
+        /// This is the counterpart of [`Rotation::add_checked`].
+        /// In release builds, this is identical to the `-` operator.
+        ///
+        /// # Panics
+        /// Panics if `rhs` is greater than 180 degrees and debug assertions are enabled.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_degrees(10.0).sub_checked(Rotation::from_degrees(20.0)), Rotation::from_degrees(350.0));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn sub_checked(self, rhs: Rotation) -> Rotation {
+            debug_assert!(
+                rhs.deci_degrees <= Rotation::FULL_CIRCLE / 2,
+                "Rotation step {rhs} is larger than half a turn"
+            );
+
+            self - rhs
+        }
+
+        /// Is `self` exactly one of the four cardinal directions?
+        ///
+        /// These are [`Rotation::NORTH`], [`Rotation::EAST`], [`Rotation::SOUTH`] and [`Rotation::WEST`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert!(Rotation::EAST.is_cardinal());
+        /// assert!(!Rotation::NORTHEAST.is_cardinal());
+        /// assert!(!Rotation::from_degrees(100.0).is_cardinal());
+        /// ```
+        #[inline]
+        #[must_use]
+        pub const fn is_cardinal(&self) -> bool {
+            matches!(self.deci_degrees, 0 | 900 | 1800 | 2700)
+        }
+
+        /// Is `self` exactly one of the four diagonal directions?
+        ///
+        /// These are [`Rotation::NORTHEAST`], [`Rotation::SOUTHEAST`], [`Rotation::SOUTHWEST`] and [`Rotation::NORTHWEST`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert!(Rotation::NORTHEAST.is_diagonal());
+        /// assert!(!Rotation::EAST.is_diagonal());
+        /// assert!(!Rotation::from_degrees(100.0).is_diagonal());
+        /// ```
+        #[inline]
+        #[must_use]
+        pub const fn is_diagonal(&self) -> bool {
+            matches!(self.deci_degrees, 450 | 1350 | 2250 | 3150)
+        }
+    }
+
+    // Constants
+    impl Rotation {
+        /// The number of deci-degrees that make up a full circle
+        pub const FULL_CIRCLE: u16 = 3600;
+
+        /// The direction that points straight up
+        pub const NORTH: Rotation = Rotation { deci_degrees: 0 };
+
+        /// The direction that points straight right
+        pub const EAST: Rotation = Rotation { deci_degrees: 900 };
+        /// The direction that points straight down
+        pub const SOUTH: Rotation = Rotation { deci_degrees: 1800 };
+        /// The direction that points straight left
+        pub const WEST: Rotation = Rotation { deci_degrees: 2700 };
+
+        /// The direction that points halfway between up and right
+        pub const NORTHEAST: Rotation = Rotation { deci_degrees: 450 };
+        /// The direction that points halfway between down and right
+        pub const SOUTHEAST: Rotation = Rotation { deci_degrees: 1350 };
+        /// The direction that points halfway between down and left
+        pub const SOUTHWEST: Rotation = Rotation { deci_degrees: 2250 };
+        /// The direction that points halfway between left and up
+        pub const NORTHWEST: Rotation = Rotation { deci_degrees: 3150 };
+
+        /// The direction that points halfway between north and northeast
+        pub const NORTH_NORTHEAST: Rotation = Rotation { deci_degrees: 225 };
+        /// The direction that points halfway between east and northeast
+        pub const EAST_NORTHEAST: Rotation = Rotation { deci_degrees: 675 };
+        /// The direction that points halfway between east and southeast
+        pub const EAST_SOUTHEAST: Rotation = Rotation { deci_degrees: 1125 };
+        /// The direction that points halfway between south and southeast
+        pub const SOUTH_SOUTHEAST: Rotation = Rotation { deci_degrees: 1575 };
+        /// The direction that points halfway between south and southwest
+        pub const SOUTH_SOUTHWEST: Rotation = Rotation { deci_degrees: 2025 };
+        /// The direction that points halfway between west and southwest
+        pub const WEST_SOUTHWEST: Rotation = Rotation { deci_degrees: 2475 };
+        /// The direction that points halfway between west and northwest
+        pub const WEST_NORTHWEST: Rotation = Rotation { deci_degrees: 2925 };
+        /// The direction that points halfway between north and northwest
+        pub const NORTH_NORTHWEST: Rotation = Rotation { deci_degrees: 3375 };
+    }
+
+    // Conversion methods
+    impl Rotation {
+        /// Constructs a [`Rotation`](crate::orientation::Direction) from a [`Vec2`](glam::Vec2)
+        ///
+        /// If both x and y are nearly 0 (the magnitude is less than [`SINGULARITY_EPSILON`]),
+        /// [`Err(NearlySingularConversion)`] will be returned instead.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_vec2(Vec2::new(0.0, 1.0)), Ok(Rotation::NORTH));
+        /// ```
+        #[inline]
+        pub fn from_vec2(vec: Vec2) -> Result<Rotation, NearlySingularConversion> {
+            Rotation::from_vec2_with_epsilon(vec, SINGULARITY_EPSILON)
+        }
+
+        /// Constructs a [`Rotation`] from a [`Vec2`](glam::Vec2), treating vectors shorter than `epsilon` as singular
+        ///
+        /// If the magnitude of `vec` is less than `epsilon`, [`Err(NearlySingularConversion)`] will be returned instead.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::errors::NearlySingularConversion;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let small = Vec2::new(0.0, 0.01);
+        /// assert_eq!(Rotation::from_vec2_with_epsilon(small, 0.001), Ok(Rotation::NORTH));
+        /// assert_eq!(Rotation::from_vec2_with_epsilon(small, 0.1), Err(NearlySingularConversion));
+        /// ```
+        #[inline]
+        pub fn from_vec2_with_epsilon(
+            vec: Vec2,
+            epsilon: f32,
+        ) -> Result<Rotation, NearlySingularConversion> {
+            if vec.length_squared() < epsilon * epsilon {
+                Err(NearlySingularConversion)
+            } else {
+                let radians = float_math::atan2(vec.x, vec.y);
+                Ok(Rotation::from_radians(radians))
+            }
+        }
+
+        /// Constructs a [`Rotation`] from a [`Vec2`](glam::Vec2), falling back to `default` for nearly-zero vectors
+        ///
+        /// This is the infallible counterpart of [`Rotation::from_vec2`],
+        /// using the same threshold to decide when a vector is too small to have a meaningful angle.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::orientation::{Orientation, Rotation};
+        ///
+        /// Rotation::from_vec2_or(Vec2::new(1.0, 0.0), Rotation::NORTH).assert_approx_eq(Rotation::EAST);
+        /// assert_eq!(Rotation::from_vec2_or(Vec2::ZERO, Rotation::SOUTH), Rotation::SOUTH);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn from_vec2_or(vec: Vec2, default: Rotation) -> Rotation {
+            Rotation::from_vec2(vec).unwrap_or(default)
+        }
+
+        /// Converts this direction into a [`Vec2`](glam::Vec2) with magnitude 1
+        #[inline]
+        #[must_use]
+        pub fn into_vec2(self) -> Vec2 {
+            let radians = self.into_radians();
+            Vec2::new(float_math::sin(radians), float_math::cos(radians))
+        }
+
+        /// Converts this direction into a [`Vec2`](glam::Vec2) with the supplied `magnitude`
+        ///
+        /// This is equivalent to `self.into_vec2() * magnitude`, and is useful for turning a heading and a speed into a velocity.
+        /// Negative magnitudes point the opposite way.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let velocity = Rotation::SOUTH.into_vec2_with_magnitude(3.0);
+        ///
+        /// assert!(velocity.abs_diff_eq(Vec2::new(0.0, -3.0), 1e-5));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn into_vec2_with_magnitude(self, magnitude: f32) -> Vec2 {
+            self.into_vec2() * magnitude
+        }
+
+        /// Converts this rotation into a 2x2 rotation matrix
+        ///
+        /// Multiplying a [`Vec2`](glam::Vec2) by this matrix rotates it clockwise by `self`,
+        /// matching the clockwise-from-north convention used throughout this crate.
+        /// When rotating many vectors by the same angle, this is cheaper than converting each one via [`Direction`](crate::orientation::Direction).
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let matrix = Rotation::EAST.to_mat2();
+        /// let rotated = matrix * Vec2::new(0.0, 1.0);
+        ///
+        /// assert!(rotated.abs_diff_eq(Vec2::new(1.0, 0.0), 1e-6));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn to_mat2(self) -> Mat2 {
+            let radians = self.into_radians();
+            let (sin, cos) = (float_math::sin(radians), float_math::cos(radians));
+
+            Mat2::from_cols(Vec2::new(cos, -sin), Vec2::new(sin, cos))
+        }
+
+        /// Rotates `vector` clockwise by `self`, preserving its magnitude
+        ///
+        /// Unlike converting via [`Direction`](crate::orientation::Direction), this does not normalize the vector,
+        /// and so works for vectors of any length (including zero).
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let rotated = Rotation::EAST.rotate_vec2(Vec2::new(0.0, 2.0));
+        ///
+        /// assert!(rotated.abs_diff_eq(Vec2::new(2.0, 0.0), 1e-6));
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn rotate_vec2(self, vector: Vec2) -> Vec2 {
+            self.to_mat2() * vector
+        }
+
+        /// Construct a [`Direction`](crate::orientation::Direction) from radians, measured clockwise from midnight
+        ///
+        /// Non-finite values (infinities and `NaN`) have no meaningful angle, and produce [`Rotation::NORTH`].
+        #[must_use]
+        #[inline]
+        pub fn from_radians(radians: impl Into<f32>) -> Rotation {
+            use core::f32::consts::TAU;
+
+            let radians: f32 = radians.into();
+            if !radians.is_finite() {
+                return Rotation::NORTH;
+            }
+
+            let normalized_radians: f32 = float_math::rem_euclid(radians, TAU);
+
+            // Tiny negative values can be normalized to exactly a full circle
+            Rotation::new((normalized_radians * 3600. / TAU) as u16)
+        }
+
+        /// Converts this direction into radians, measured clockwise from midnight
+        #[inline]
+        #[must_use]
+        pub fn into_radians(self) -> f32 {
+            self.deci_degrees as f32 * core::f32::consts::TAU / 3600.
+        }
+
+        /// Construct a [`Rotation`] from radians, using the standard mathematical convention
+        ///
+        /// Unlike [`Rotation::from_radians`], which measures clockwise from north (+y),
+        /// this measures counterclockwise from east (+x), as used by [`f32::atan2`] and most math textbooks.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Orientation, Rotation};
+        /// use core::f32::consts::{FRAC_PI_2, PI};
+        ///
+        /// Rotation::from_math_radians(0.0).assert_approx_eq(Rotation::EAST);
+        /// Rotation::from_math_radians(FRAC_PI_2).assert_approx_eq(Rotation::NORTH);
+        /// Rotation::from_math_radians(PI).assert_approx_eq(Rotation::WEST);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn from_math_radians(radians: impl Into<f32>) -> Rotation {
+            Rotation::from_radians(core::f32::consts::FRAC_PI_2 - radians.into())
+        }
+
+        /// Converts this rotation into radians, using the standard mathematical convention
+        ///
+        /// Unlike [`Rotation::into_radians`], which measures clockwise from north (+y),
+        /// this measures counterclockwise from east (+x), as used by [`f32::atan2`] and most math textbooks.
+        /// The returned value is always within `[0, TAU)`.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        /// use core::f32::consts::{FRAC_PI_2, PI};
+        ///
+        /// assert_eq!(Rotation::EAST.into_math_radians(), 0.0);
+        /// assert!((Rotation::NORTH.into_math_radians() - FRAC_PI_2).abs() < 1e-6);
+        /// assert!((Rotation::WEST.into_math_radians() - PI).abs() < 1e-6);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn into_math_radians(self) -> f32 {
+            // Reflecting across the northeast diagonal swaps the two conventions
+            let math_rotation = Rotation::EAST - self;
+            math_rotation.into_radians()
+        }
+
+        /// Construct a [`Direction`](crate::orientation::Direction) from degrees, measured clockwise from midnight
+        ///
+        /// Non-finite values (infinities and `NaN`) have no meaningful angle, and produce [`Rotation::NORTH`].
+        #[must_use]
+        #[inline]
+        pub fn from_degrees(degrees: impl Into<f32>) -> Rotation {
+            let degrees: f32 = degrees.into();
+            if !degrees.is_finite() {
+                return Rotation::NORTH;
+            }
+
+            let normalized_degrees: f32 = float_math::rem_euclid(degrees, 360.0);
+
+            // Tiny negative values can be normalized to exactly a full circle
+            Rotation::new((normalized_degrees * 10.0) as u16)
+        }
+
+        /// Converts this direction into degrees, measured clockwise from midnight
+        #[inline]
+        #[must_use]
+        pub fn into_degrees(self) -> f32 {
+            self.deci_degrees as f32 / 10.
+        }
+
+        /// Construct a [`Rotation`] from a user-facing value in degrees, measured clockwise from midnight
+        ///
+        /// Unlike [`Rotation::from_degrees`], the value is rounded to the nearest tenth of a degree,
+        /// so values produced by [`Rotation::into_display_degrees`] always round-trip exactly.
+        /// Values outside of `[0.0, 360.0)` (including `360.0` and `-0.0`) are wrapped into that range.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_display_degrees(12.3), Rotation::new(123));
+        /// assert_eq!(Rotation::from_display_degrees(360.0), Rotation::NORTH);
+        /// assert_eq!(Rotation::from_display_degrees(-90.0), Rotation::WEST);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn from_display_degrees(degrees: f32) -> Rotation {
+            let deci_degrees = float_math::round(degrees * 10.0) as i32;
+
+            Rotation {
+                deci_degrees: deci_degrees.rem_euclid(Rotation::FULL_CIRCLE as i32) as u16,
+            }
+        }
+
+        /// Converts this rotation into a user-facing value in degrees, measured clockwise from midnight
+        ///
+        /// The returned value is always within `[0.0, 360.0)`.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::WEST.into_display_degrees(), 270.0);
+        /// assert_eq!(Rotation::new(3599).into_display_degrees(), 359.9);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn into_display_degrees(self) -> f32 {
+            // `deci_degrees` is always less than `FULL_CIRCLE`
+            self.deci_degrees as f32 / 10.
+        }
+
+        /// Construct a [`Rotation`] from a fraction of a full circle, measured clockwise from midnight
+        ///
+        /// Values outside of `[0.0, 1.0)` are wrapped into that range.
+        /// Non-finite values (infinities and `NaN`) have no meaningful angle, and produce [`Rotation::NORTH`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::from_turns(0.25), Rotation::EAST);
+        /// assert_eq!(Rotation::from_turns(-0.5), Rotation::SOUTH);
+        /// assert_eq!(Rotation::from_turns(1.0), Rotation::NORTH);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn from_turns(turns: f32) -> Rotation {
+            if !turns.is_finite() {
+                return Rotation::NORTH;
+            }
+
+            let normalized_turns = float_math::rem_euclid(turns, 1.0);
+
+            // Tiny negative values can be normalized to exactly a full circle
+            Rotation::new((normalized_turns * Rotation::FULL_CIRCLE as f32) as u16)
+        }
+
+        /// Converts this rotation into a fraction of a full circle, measured clockwise from midnight
+        ///
+        /// The returned value is always within `[0.0, 1.0)`,
+        /// which makes it convenient for shader parameters and indexing into lookup tables.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::NORTH.as_turns(), 0.0);
+        /// assert_eq!(Rotation::WEST.as_turns(), 0.75);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn as_turns(self) -> f32 {
+            self.deci_degrees as f32 / Rotation::FULL_CIRCLE as f32
+        }
+
+        /// Formats this rotation in the chosen [`AngleUnit`], measured clockwise from midnight
+        ///
+        /// The returned value implements [`Display`], and formats the rotation lazily, without allocating.
+        /// Degrees and radians respect the precision of the format string,
+        /// and default to one and three decimal places respectively.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{AngleUnit, Rotation};
+        ///
+        /// let rotation = Rotation::new(1234);
+        ///
+        /// assert_eq!(rotation.display_as(AngleUnit::Degrees).to_string(), "123.4°");
+        /// assert_eq!(rotation.display_as(AngleUnit::Radians).to_string(), "2.154 rad");
+        /// assert_eq!(rotation.display_as(AngleUnit::DeciDegrees).to_string(), "1234 deci-degrees");
+        /// assert_eq!(format!("{:.5}", rotation.display_as(AngleUnit::Radians)), "2.15374 rad");
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn display_as(self, unit: AngleUnit) -> impl Display {
+            RotationDisplay {
+                rotation: self,
+                unit,
+            }
+        }
+    }
+
+    impl Display for Rotation {
+        /// Formats the rotation in degrees, clockwise from midnight
+        ///
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// assert_eq!(Rotation::EAST.to_string(), "90.0°");
+        /// assert_eq!(Rotation::new(1234).to_string(), "123.4°");
+        /// ```
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{:.1}°", self.into_degrees())
+        }
+    }
+
+    /// The units that a [`Rotation`] can be formatted in, using [`Rotation::display_as`]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AngleUnit {
+        /// Degrees, such as `90.0°`, matching the [`Display`] impl of [`Rotation`]
+        Degrees,
+        /// Radians, such as `1.571 rad`
+        Radians,
+        /// Tenths of a degree, such as `900 deci-degrees`, matching the internal representation of [`Rotation`]
+        DeciDegrees,
+    }
+
+    /// Formats a [`Rotation`] in a particular [`AngleUnit`], returned by [`Rotation::display_as`]
+    struct RotationDisplay {
+        rotation: Rotation,
+        unit: AngleUnit,
+    }
+
+    impl Display for RotationDisplay {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            match self.unit {
+                AngleUnit::Degrees => {
+                    let precision = f.precision().unwrap_or(1);
+                    write!(f, "{:.*}°", precision, self.rotation.into_degrees())
+                }
+                AngleUnit::Radians => {
+                    let precision = f.precision().unwrap_or(3);
+                    write!(f, "{:.*} rad", precision, self.rotation.into_radians())
+                }
+                AngleUnit::DeciDegrees => {
+                    write!(f, "{} deci-degrees", self.rotation.deci_degrees)
+                }
+            }
+        }
+    }
+
+    impl Add for Rotation {
+        type Output = Rotation;
+        fn add(self, rhs: Self) -> Rotation {
+            Rotation::new(self.deci_degrees + rhs.deci_degrees)
+        }
+    }
+
+    impl Sub for Rotation {
+        type Output = Rotation;
+        fn sub(self, rhs: Self) -> Rotation {
+            if self.deci_degrees >= rhs.deci_degrees {
+                Rotation::new(self.deci_degrees - rhs.deci_degrees)
+            } else {
+                Rotation::new(self.deci_degrees + Rotation::FULL_CIRCLE - rhs.deci_degrees)
+            }
+        }
+    }
+
+    impl AddAssign for Rotation {
+        fn add_assign(&mut self, rhs: Self) {
+            self.deci_degrees = (self.deci_degrees + rhs.deci_degrees) % Rotation::FULL_CIRCLE;
+        }
+    }
+
+    impl SubAssign for Rotation {
+        fn sub_assign(&mut self, rhs: Self) {
+            // Be sure to avoid overflow when subtracting
+            if self.deci_degrees > rhs.deci_degrees {
+                self.deci_degrees = self.deci_degrees - rhs.deci_degrees;
+            } else {
+                self.deci_degrees = Rotation::FULL_CIRCLE - (rhs.deci_degrees - self.deci_degrees);
+            }
+        }
+    }
+
+    impl Neg for Rotation {
+        type Output = Rotation;
+        fn neg(self) -> Rotation {
+            // `Rotation::new` wraps the full circle produced by negating `NORTH` back to 0
+            Rotation::new(Rotation::FULL_CIRCLE - self.deci_degrees)
+        }
+    }
+
+    impl Mul<f32> for Rotation {
+        type Output = Rotation;
+        fn mul(self, rhs: f32) -> Rotation {
+            Rotation::from_degrees(self.into_degrees() * rhs)
+        }
+    }
+
+    impl Mul<Rotation> for f32 {
+        type Output = Rotation;
+        fn mul(self, rhs: Rotation) -> Rotation {
+            Rotation::from_degrees(rhs.into_degrees() * self)
+        }
+    }
+
+    impl Div<f32> for Rotation {
+        type Output = Rotation;
+        fn div(self, rhs: f32) -> Rotation {
+            Rotation::from_degrees(self.into_degrees() / rhs)
+        }
+    }
+
+    impl Div<Rotation> for f32 {
+        type Output = Rotation;
+        fn div(self, rhs: Rotation) -> Rotation {
+            Rotation::from_degrees(self / rhs.into_degrees())
+        }
     }
+}
+
+mod direction {
+    use super::float_math;
+    use super::{Orientation, Rotation, RotationDirection, SINGULARITY_EPSILON};
+    use crate::errors::NearlySingularConversion;
+    #[cfg(feature = "std")]
+    use bevy_ecs::prelude::Component;
+    use core::f32::consts::{PI, SQRT_2, TAU};
+    use core::fmt::{Display, Formatter};
+    use core::hash::{Hash, Hasher};
+    use core::ops::{Add, Div, Mul, Neg, Sub};
+    use glam::{const_vec2, Vec2, Vec3};
+
+    /// The sine of 22.5 degrees, used by the 16-point compass constants
+    const SIN_22_5: f32 = 0.382_683_43;
+    /// The cosine of 22.5 degrees, used by the 16-point compass constants
+    const COS_22_5: f32 = 0.923_879_5;
+
+    /// A 2D unit vector that represents a direction
+    ///
+    /// Its magnitude is always one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Direction;
+    /// use bevy::math::Vec2;
+    ///
+    /// assert_eq!(Direction::NORTH.unit_vector(), Vec2::new(0.0, 1.0));
+    /// assert_eq!(Direction::try_from(Vec2::ONE), Ok(Direction::NORTHEAST));
+    ///
+    /// assert_eq!(Direction::SOUTH * 3.0, Vec2::new(0.0, -3.0));
+    /// assert_eq!(Direction::EAST / 2.0, Vec2::new(0.5, 0.0));
+    /// ```
+    ///
+    /// # Equality
+    ///
+    /// Floating point vectors cannot be reliably compared or hashed,
+    /// so directions are considered equal if they round to the same tenth of a degree.
+    /// [`Direction::NEUTRAL`] is only equal to itself.
+    /// This allows [`Direction`] to be used as the key of a `HashMap` or `HashSet`.
+    ///
+    /// ```rust
+    /// use leafwing_2d::orientation::Direction;
+    /// use bevy::math::Vec2;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut directions = HashSet::new();
+    /// directions.insert(Direction::NORTHEAST);
+    /// directions.insert(Direction::new(Vec2::new(1.0, 1.0001)));
+    ///
+    /// assert_eq!(directions.len(), 1);
+    /// ```
+    #[derive(Clone, Copy, Debug)]
+    #[cfg_attr(feature = "std", derive(Component))]
+    pub struct Direction {
+        pub(crate) unit_vector: Vec2,
+    }
+
+    impl Direction {
+        /// The value used to compare and hash directions
+        ///
+        /// This is the angle from north, rounded to the nearest tenth of a degree.
+        /// [`Direction::NEUTRAL`] uses `u16::MAX`, which can never be a valid angle.
+        fn canonical_deci_degrees(&self) -> u16 {
+            if self.is_neutral() {
+                return u16::MAX;
+            }
+
+            let radians = float_math::atan2(self.unit_vector.x, self.unit_vector.y);
+            let deci_degrees = float_math::round(radians * 1800.0 / PI) as i32;
+
+            deci_degrees.rem_euclid(Rotation::FULL_CIRCLE as i32) as u16
+        }
+    }
+
+    impl PartialEq for Direction {
+        fn eq(&self, other: &Direction) -> bool {
+            self.canonical_deci_degrees() == other.canonical_deci_degrees()
+        }
+    }
+
+    impl Eq for Direction {}
+
+    impl Hash for Direction {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.canonical_deci_degrees().hash(state);
+        }
+    }
+
+    impl Default for Direction {
+        /// [`Direction::NORTH`] is the default direction,
+        /// as it is consistent with the default [`Rotation`]
+        fn default() -> Direction {
+            Direction::NORTH
+        }
+    }
+
+    impl Direction {
+        /// Creates a new [`Direction`] from a [`Vec2`]
+        ///
+        /// The [`Vec2`] will be normalized to have a magnitude of 1.
+        /// Use [`Direction::from_vec2_with_magnitude`] instead to keep the magnitude.
+        ///
+        /// # Panics
+        /// Panics if the supplied vector is shorter than [`SINGULARITY_EPSILON`].
+        /// Use [`Direction::try_from`] to handle this case instead.
+        #[must_use]
+        #[inline]
+        pub fn new(vec2: Vec2) -> Self {
+            match Direction::from_vec2_with_epsilon(vec2, SINGULARITY_EPSILON) {
+                Ok(direction) => direction,
+                Err(_) => panic!("Supplied a Vec2 with length nearly 0 to a Direction."),
+            }
+        }
+
+        /// Creates a new [`Direction`] from a [`Vec2`], treating vectors shorter than `epsilon` as singular
+        ///
+        /// If the magnitude of `vec2` is less than `epsilon`, [`Err(NearlySingularConversion)`] will be returned instead.
+        /// [`Direction::try_from`] uses [`SINGULARITY_EPSILON`] for this threshold.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::Vec2;
+        /// use leafwing_2d::errors::NearlySingularConversion;
+        /// use leafwing_2d::orientation::Direction;
+        ///
+        /// let small = Vec2::new(0.01, 0.0);
+        /// assert_eq!(Direction::from_vec2_with_epsilon(small, 0.001), Ok(Direction::EAST));
+        /// assert_eq!(Direction::from_vec2_with_epsilon(small, 0.1), Err(NearlySingularConversion));
+        /// ```
+        #[inline]
+        pub fn from_vec2_with_epsilon(
+            vec2: Vec2,
+            epsilon: f32,
+        ) -> Result<Direction, NearlySingularConversion> {
+            let magnitude = vec2.length();
+
+            if magnitude < epsilon {
+                Err(NearlySingularConversion)
+            } else {
+                Ok(Direction {
+                    unit_vector: vec2 / magnitude,
+                })
+            }
+        }
+
+        /// Creates a new [`Direction`] from a [`Vec2`] that is already normalized
+        ///
+        /// Unlike [`Direction::new`], this is a `const fn`, and so can be used to declare your own direction constants.
+        ///
+        /// The supplied vector is used as-is: it is the caller's responsibility to ensure that it has a magnitude of 1.
+        /// The vector cannot be inspected in a `const` context, so this is not checked.
+        /// Passing in a vector of any other length will not cause undefined behavior,
+        /// but will produce incorrect results in methods that assume that directions are unit vectors.
+        ///
+        /// # Example
+        /// ```rust
+        /// use bevy::math::const_vec2;
+        /// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+        ///
+        /// // A 3-4-5 triangle, scaled down to unit length
+        /// const STEEP_SLOPE: Direction = Direction::new_unchecked(const_vec2!([0.6, 0.8]));
+        ///
+        /// STEEP_SLOPE.assert_approx_eq(Direction::new(bevy::math::Vec2::new(3.0, 4.0)));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub const fn new_unchecked(unit_vector: Vec2) -> Self {
+            Self { unit_vector }
+        }
+
+        /// Splits a [`Vec2`] into its [`Direction`] and its magnitude
+        ///
+        /// This is useful when decomposing a velocity into a heading and a speed.
+        /// If the magnitude is nearly 0 (less than [`SINGULARITY_EPSILON`]),
+        /// `(Direction::NEUTRAL, 0.0)` is returned instead.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        /// use bevy::math::Vec2;
+        ///
+        /// let (direction, magnitude) = Direction::from_vec2_with_magnitude(Vec2::new(0.0, -2.0));
+        /// assert_eq!(direction, Direction::SOUTH);
+        /// assert_eq!(magnitude, 2.0);
+        ///
+        /// assert_eq!(Direction::from_vec2_with_magnitude(Vec2::ZERO), (Direction::NEUTRAL, 0.0));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn from_vec2_with_magnitude(vec2: Vec2) -> (Direction, f32) {
+            let magnitude = vec2.length();
+
+            if magnitude < SINGULARITY_EPSILON {
+                (Direction::NEUTRAL, 0.0)
+            } else {
+                (
+                    Direction {
+                        unit_vector: vec2 / magnitude,
+                    },
+                    magnitude,
+                )
+            }
+        }
+
+        /// Returns the raw underlying [`Vec2`] unit vector of this direction
+        ///
+        /// This will always have a magnitude of 1, unless it is [`Direction::NEUTRAL`]
+        #[must_use]
+        #[inline]
+        pub const fn unit_vector(&self) -> Vec2 {
+            self.unit_vector
+        }
+
+        /// Is this direction [`Direction::NEUTRAL`]?
+        #[must_use]
+        #[inline]
+        pub fn is_neutral(&self) -> bool {
+            self.unit_vector == Vec2::ZERO
+        }
+
+        /// Is `self` within the cone that extends `half_angle` to either side of `axis`?
+        ///
+        /// Directions that lie exactly on the edge of the cone are considered to be within it.
+        ///
+        /// # Errors
+        /// Returns [`NearlySingularConversion`] if either `self` or `axis` is [`Direction::NEUTRAL`],
+        /// as neutral directions do not point anywhere.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Rotation};
+        ///
+        /// let half_angle = Rotation::from_degrees(45.0);
+        ///
+        /// assert_eq!(Direction::NORTHEAST.is_within_cone(Direction::NORTH, half_angle), Ok(true));
+        /// assert_eq!(Direction::EAST.is_within_cone(Direction::NORTH, half_angle), Ok(false));
+        /// assert!(Direction::NEUTRAL.is_within_cone(Direction::NORTH, half_angle).is_err());
+        /// ```
+        #[inline]
+        pub fn is_within_cone(
+            self,
+            axis: Direction,
+            half_angle: Rotation,
+        ) -> Result<bool, NearlySingularConversion> {
+            if self.is_neutral() || axis.is_neutral() {
+                return Err(NearlySingularConversion);
+            }
 
-    impl Div<Rotation> for f32 {
-        type Output = Rotation;
-        fn div(self, rhs: Rotation) -> Rotation {
-            Rotation::from_degrees(self / rhs.into_degrees())
+            Ok(self.distance(axis) <= half_angle)
         }
-    }
-}
 
-mod direction {
-    use bevy_ecs::prelude::Component;
-    use bevy_math::{const_vec2, Vec2, Vec3};
-    use core::ops::{Add, Div, Mul, Neg, Sub};
-    use derive_more::Display;
-    use std::f32::consts::SQRT_2;
+        /// Clamps `self` to lie within the cone that extends `half_angle` to either side of `axis`
+        ///
+        /// Directions within the cone (as determined by [`Direction::is_within_cone`]) are returned unchanged.
+        /// Directions outside of it are snapped to the nearest edge of the cone, which is a unit vector.
+        /// Directions exactly opposite to `axis` are snapped to the clockwise edge, matching [`Orientation::rotation_direction`].
+        ///
+        /// [`Direction::NEUTRAL`] does not point anywhere, and so is returned unchanged.
+        /// If `axis` is neutral, the cone is undefined and `self` is also returned unchanged.
+        ///
+        /// This is useful for limited aiming arcs, such as a turret that can only swivel so far.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+        ///
+        /// let half_angle = Rotation::from_degrees(45.0);
+        ///
+        /// assert_eq!(Direction::NORTHEAST.clamp_to_cone(Direction::NORTH, half_angle), Direction::NORTHEAST);
+        /// Direction::EAST.clamp_to_cone(Direction::NORTH, half_angle).assert_approx_eq(Direction::NORTHEAST);
+        /// Direction::SOUTHWEST.clamp_to_cone(Direction::NORTH, half_angle).assert_approx_eq(Direction::NORTHWEST);
+        /// ```
+        #[must_use]
+        pub fn clamp_to_cone(self, axis: Direction, half_angle: Rotation) -> Direction {
+            match self.is_within_cone(axis, half_angle) {
+                Ok(false) => (),
+                // Already inside the cone, or there is no meaningful cone to clamp to
+                Ok(true) | Err(NearlySingularConversion) => return self,
+            }
 
-    /// A 2D unit vector that represents a direction
-    ///
-    /// Its magnitude is always one.
-    ///
-    /// # Example
-    /// ```rust
-    /// use leafwing_2d::orientation::Direction;
-    /// use bevy::math::Vec2;
-    ///
-    /// assert_eq!(Direction::NORTH.unit_vector(), Vec2::new(0.0, 1.0));
-    /// assert_eq!(Direction::try_from(Vec2::ONE), Ok(Direction::NORTHEAST));
-    ///
-    /// assert_eq!(Direction::SOUTH * 3.0, Vec2::new(0.0, -3.0));
-    /// assert_eq!(Direction::EAST / 2.0, Vec2::new(0.5, 0.0));
-    /// ```
-    #[derive(Component, Clone, Copy, Debug, PartialEq, Display)]
-    pub struct Direction {
-        pub(crate) unit_vector: Vec2,
-    }
+            let edge_offset = match axis.rotation_direction(self) {
+                RotationDirection::Clockwise => half_angle,
+                RotationDirection::CounterClockwise => -half_angle,
+            };
 
-    impl Default for Direction {
-        /// [`Direction::NORTH`] is the default direction,
-        /// as it is consistent with the default [`Rotation`]
-        fn default() -> Direction {
-            Direction::NORTH
+            axis.then(edge_offset.into())
         }
-    }
 
-    impl Direction {
-        /// Creates a new [`Direction`] from a [`Vec2`]
+        /// Computes the [`Rotation`] that, when applied to `self`, yields `other`
         ///
-        /// The [`Vec2`] will be normalized to have a magnitude of 1.
+        /// Like all [`Rotation`]s, this is measured clockwise:
+        /// a counterclockwise turn of 90 degrees is returned as 270 degrees.
+        /// Use [`Orientation::rotation_direction`] if you need to know which way is shorter.
         ///
-        /// # Panics
-        /// Panics if the supplied vector has length zero.
+        /// # Errors
+        /// Returns [`NearlySingularConversion`] if either `self` or `other` is [`Direction::NEUTRAL`],
+        /// as neutral directions cannot be rotated.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+        ///
+        /// let rotation = Direction::NORTH.rotation_to(Direction::EAST).unwrap();
+        /// rotation.assert_approx_eq(Rotation::EAST);
+        ///
+        /// let new_direction: Direction = (Rotation::from(Direction::NORTH) + rotation).into();
+        /// new_direction.assert_approx_eq(Direction::EAST);
+        ///
+        /// assert!(Direction::NEUTRAL.rotation_to(Direction::EAST).is_err());
+        /// ```
+        #[inline]
+        pub fn rotation_to(self, other: Direction) -> Result<Rotation, NearlySingularConversion> {
+            if self.is_neutral() || other.is_neutral() {
+                return Err(NearlySingularConversion);
+            }
+
+            let self_rotation: Rotation = self.into();
+            let other_rotation: Rotation = other.into();
+
+            Ok(other_rotation - self_rotation)
+        }
+
+        /// Treats `delta` as a rotation clockwise from north, and applies it to `self`
+        ///
+        /// This is the [`Direction`] equivalent of adding two [`Rotation`]s,
+        /// and differs from [`Add`], which blends the underlying vectors.
+        /// The result is computed directly from the unit vectors, without rounding to the nearest tenth of a degree.
+        ///
+        /// [`Direction::NEUTRAL`] points nowhere, and so is returned unchanged when it is `self`.
+        /// A neutral `delta` is treated as no rotation, matching its conversion into [`Rotation::NORTH`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation};
+        ///
+        /// Direction::EAST.then(Direction::NORTHEAST).assert_approx_eq(Direction::SOUTHEAST);
+        /// Direction::WEST.then(Direction::SOUTH).assert_approx_eq(Direction::EAST);
+        ///
+        /// assert_eq!(Direction::NORTH.then(Direction::NEUTRAL), Direction::NORTH);
+        /// assert_eq!(Direction::NEUTRAL.then(Direction::EAST), Direction::NEUTRAL);
+        /// ```
         #[must_use]
         #[inline]
-        pub fn new(vec2: Vec2) -> Self {
-            if vec2.length_squared() == 0.0 {
-                panic!("Supplied a Vec2 with length 0 to a Direction.")
-            };
+        pub fn then(self, delta: Direction) -> Direction {
+            if delta.is_neutral() {
+                return self;
+            }
 
-            Self {
-                unit_vector: vec2.normalize(),
+            // The sine and cosine of the clockwise angle of `delta`
+            let (sin, cos) = (delta.unit_vector.x, delta.unit_vector.y);
+            let vector = self.unit_vector;
+
+            Direction {
+                unit_vector: Vec2::new(
+                    cos * vector.x + sin * vector.y,
+                    cos * vector.y - sin * vector.x,
+                ),
             }
         }
 
-        /// Returns the raw underlying [`Vec2`] unit vector of this direction
+        /// Scales the underlying unit vector by `magnitude`, returning a displacement
         ///
-        /// This will always have a magnitude of 1, unless it is [`Direction::NEUTRAL`]
+        /// This is equivalent to `self * magnitude`,
+        /// but makes it clear that the result is a [`Vec2`] rather than a [`Direction`].
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        /// use bevy::math::Vec2;
+        ///
+        /// assert_eq!(Direction::SOUTH.scaled(3.0), Vec2::new(0.0, -3.0));
+        /// ```
         #[must_use]
         #[inline]
-        pub const fn unit_vector(&self) -> Vec2 {
-            self.unit_vector
+        pub fn scaled(self, magnitude: f32) -> Vec2 {
+            self * magnitude
+        }
+
+        /// Returns the [`Direction`] that bisects `self` and `other`
+        ///
+        /// If `self` and `other` point in opposite directions, there is no unique bisector
+        /// and [`Direction::NEUTRAL`] is returned instead.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation};
+        ///
+        /// Direction::NORTH
+        ///     .halfway(Direction::EAST)
+        ///     .assert_approx_eq(Direction::NORTHEAST);
+        ///
+        /// assert_eq!(Direction::NORTH.halfway(Direction::SOUTH), Direction::NEUTRAL);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn halfway(self, other: Direction) -> Direction {
+            Direction::try_from(self + other).unwrap_or(Direction::NEUTRAL)
+        }
+
+        /// Interpolates between `self` and `other` along the shorter arc of the unit circle
+        ///
+        /// The angle changes uniformly with `s`: `0.0` returns `self`, `1.0` returns `other`,
+        /// and `0.5` returns the direction exactly halfway around the arc between them.
+        /// Values of `s` outside of `[0.0, 1.0]` extrapolate along the same arc.
+        ///
+        /// Unlike [`Direction::halfway`] and adding directions together, this never collapses to [`Direction::NEUTRAL`]:
+        /// if `self` and `other` point in opposite directions, the clockwise arc is used.
+        /// If either input is [`Direction::NEUTRAL`], there is no arc to follow, and [`Direction::NEUTRAL`] is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+        ///
+        /// assert_eq!(Direction::NORTH.slerp(Direction::EAST, 0.5), Direction::NORTHEAST);
+        ///
+        /// Direction::NORTH
+        ///     .slerp(Direction::WEST, 1.0 / 3.0)
+        ///     .assert_approx_eq(Rotation::from_degrees(330.0));
+        ///
+        /// Direction::NORTH
+        ///     .slerp(Direction::SOUTH, 0.5)
+        ///     .assert_approx_eq(Direction::EAST);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn slerp(self, other: Direction, s: f32) -> Direction {
+            if self.is_neutral() || other.is_neutral() {
+                return Direction::NEUTRAL;
+            }
+
+            // Clockwise angles from north
+            let start = float_math::atan2(self.unit_vector.x, self.unit_vector.y);
+            let end = float_math::atan2(other.unit_vector.x, other.unit_vector.y);
+
+            // Wrap the signed difference into (-PI, PI], so that opposite directions turn clockwise
+            let delta = PI - float_math::rem_euclid(PI - (end - start), TAU);
+            let radians = start + delta * s;
+
+            Direction {
+                unit_vector: Vec2::new(float_math::sin(radians), float_math::cos(radians)),
+            }
+        }
+
+        /// The component of this direction's unit vector that lies along `axis`
+        ///
+        /// Together with [`Direction::reject_from`], this decomposes the unit vector into parts parallel and perpendicular to `axis`,
+        /// as needed when sliding along walls.
+        /// If either input is [`Direction::NEUTRAL`], [`Vec2::ZERO`] is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        /// use bevy::math::Vec2;
+        ///
+        /// let projection = Direction::NORTHEAST.project_onto(Direction::EAST);
+        /// assert_eq!(projection, Vec2::new(Direction::NORTHEAST.unit_vector().x, 0.0));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn project_onto(self, axis: Direction) -> Vec2 {
+            if self.is_neutral() || axis.is_neutral() {
+                return Vec2::ZERO;
+            }
+
+            axis.unit_vector * self.unit_vector.dot(axis.unit_vector)
+        }
+
+        /// The component of this direction's unit vector that is perpendicular to `axis`
+        ///
+        /// This is the remainder after [`Direction::project_onto`]: the two always sum to [`Direction::unit_vector`].
+        /// If either input is [`Direction::NEUTRAL`], [`Vec2::ZERO`] is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        /// use bevy::math::Vec2;
+        ///
+        /// let rejection = Direction::NORTHEAST.reject_from(Direction::EAST);
+        /// assert_eq!(rejection, Vec2::new(0.0, Direction::NORTHEAST.unit_vector().y));
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn reject_from(self, axis: Direction) -> Vec2 {
+            if self.is_neutral() || axis.is_neutral() {
+                return Vec2::ZERO;
+            }
+
+            self.unit_vector - self.project_onto(axis)
+        }
+
+        /// The average of many directions, found by summing their unit vectors and normalizing the result
+        ///
+        /// This is useful for blending the headings of a group, such as when aligning a flock.
+        /// [`Direction::NEUTRAL`] has no facing, and so does not contribute to the average.
+        /// If there are no directions to average, or they cancel each other out, [`Direction::NEUTRAL`] is returned.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::{Direction, Orientation};
+        ///
+        /// Direction::average([Direction::NORTH, Direction::EAST])
+        ///     .assert_approx_eq(Direction::NORTHEAST);
+        ///
+        /// assert_eq!(Direction::average([Direction::EAST, Direction::WEST]), Direction::NEUTRAL);
+        /// assert_eq!(Direction::average([]), Direction::NEUTRAL);
+        /// ```
+        #[must_use]
+        #[inline]
+        pub fn average(directions: impl IntoIterator<Item = Direction>) -> Direction {
+            let (sum, count) = directions
+                .into_iter()
+                .filter(|direction| !direction.is_neutral())
+                .fold((Vec2::ZERO, 0), |(sum, count), direction| {
+                    (sum + direction.unit_vector, count + 1)
+                });
+
+            if count == 0 {
+                return Direction::NEUTRAL;
+            }
+
+            // Compare the mean, rather than the sum, so that rounding errors don't accumulate with the number of directions
+            let mean = sum / count as f32;
+            if mean.length() < SINGULARITY_EPSILON {
+                Direction::NEUTRAL
+            } else {
+                Direction {
+                    unit_vector: mean.normalize(),
+                }
+            }
         }
     }
 
@@ -674,6 +2186,74 @@ mod direction {
         pub const NORTHWEST: Direction = Direction {
             unit_vector: const_vec2!([-SQRT_2 / 2.0, SQRT_2 / 2.0]),
         };
+
+        /// The direction that points halfway between north and northeast
+        pub const NORTH_NORTHEAST: Direction = Direction {
+            unit_vector: const_vec2!([SIN_22_5, COS_22_5]),
+        };
+        /// The direction that points halfway between east and northeast
+        pub const EAST_NORTHEAST: Direction = Direction {
+            unit_vector: const_vec2!([COS_22_5, SIN_22_5]),
+        };
+        /// The direction that points halfway between east and southeast
+        pub const EAST_SOUTHEAST: Direction = Direction {
+            unit_vector: const_vec2!([COS_22_5, -SIN_22_5]),
+        };
+        /// The direction that points halfway between south and southeast
+        pub const SOUTH_SOUTHEAST: Direction = Direction {
+            unit_vector: const_vec2!([SIN_22_5, -COS_22_5]),
+        };
+        /// The direction that points halfway between south and southwest
+        pub const SOUTH_SOUTHWEST: Direction = Direction {
+            unit_vector: const_vec2!([-SIN_22_5, -COS_22_5]),
+        };
+        /// The direction that points halfway between west and southwest
+        pub const WEST_SOUTHWEST: Direction = Direction {
+            unit_vector: const_vec2!([-COS_22_5, -SIN_22_5]),
+        };
+        /// The direction that points halfway between west and northwest
+        pub const WEST_NORTHWEST: Direction = Direction {
+            unit_vector: const_vec2!([-COS_22_5, SIN_22_5]),
+        };
+        /// The direction that points halfway between north and northwest
+        pub const NORTH_NORTHWEST: Direction = Direction {
+            unit_vector: const_vec2!([-SIN_22_5, COS_22_5]),
+        };
+
+        /// A direction that does not point anywhere, representing a lack of facing
+        ///
+        /// Unlike every other [`Direction`], this has a magnitude of 0.
+        /// The sync systems in [`TwoDPlugin`](crate::plugin::TwoDPlugin) will never overwrite a neutral direction:
+        /// it must be replaced explicitly.
+        pub const NEUTRAL: Direction = Direction {
+            unit_vector: const_vec2!([0.0, 0.0]),
+        };
+    }
+
+    impl Display for Direction {
+        /// Formats the direction as the nearest of the eight compass points, followed by its unit vector
+        ///
+        /// [`Direction::NEUTRAL`] is formatted as `neutral`.
+        ///
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        ///
+        /// assert_eq!(Direction::EAST.to_string(), "E [1, 0]");
+        /// assert_eq!(Direction::NEUTRAL.to_string(), "neutral");
+        /// ```
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            const COMPASS_POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+            if self.is_neutral() {
+                return write!(f, "neutral");
+            }
+
+            let rotation: Rotation = (*self).into();
+            // Each compass point covers 45 degrees, centered on the point itself
+            let index = (rotation.deci_degrees() as usize + 225) / 450 % COMPASS_POINTS.len();
+
+            write!(f, "{} {}", COMPASS_POINTS[index], self.unit_vector)
+        }
     }
 
     impl Add for Direction {
@@ -741,10 +2321,12 @@ mod direction {
 }
 
 mod conversions {
-    use super::{Direction, Rotation};
+    use super::float_math;
+    use super::{Direction, Rotation, SINGULARITY_EPSILON};
     use crate::errors::NearlySingularConversion;
-    use bevy_math::{Quat, Vec2, Vec3};
+    #[cfg(feature = "std")]
     use bevy_transform::components::{GlobalTransform, Transform};
+    use glam::{Mat2, Quat, Vec2, Vec3};
 
     impl From<Rotation> for Direction {
         fn from(rotation: Rotation) -> Direction {
@@ -756,7 +2338,7 @@ mod conversions {
 
     impl From<Direction> for Rotation {
         fn from(direction: Direction) -> Rotation {
-            let radians = f32::atan2(direction.unit_vector().x, direction.unit_vector().y);
+            let radians = float_math::atan2(direction.unit_vector().x, direction.unit_vector().y);
             Rotation::from_radians(radians)
         }
     }
@@ -775,17 +2357,17 @@ mod conversions {
         }
     }
 
+    impl From<Rotation> for Mat2 {
+        fn from(rotation: Rotation) -> Mat2 {
+            rotation.to_mat2()
+        }
+    }
+
     impl TryFrom<Vec2> for Direction {
         type Error = NearlySingularConversion;
 
         fn try_from(vec2: Vec2) -> Result<Direction, NearlySingularConversion> {
-            if vec2.length_squared() == 0.0 {
-                Err(NearlySingularConversion)
-            } else {
-                Ok(Direction {
-                    unit_vector: vec2.normalize(),
-                })
-            }
+            Direction::from_vec2_with_epsilon(vec2, SINGULARITY_EPSILON)
         }
     }
 
@@ -830,51 +2412,182 @@ mod conversions {
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Transform> for Direction {
         fn from(transform: Transform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<GlobalTransform> for Direction {
         fn from(transform: GlobalTransform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Direction> for Transform {
         fn from(direction: Direction) -> Self {
             Transform::from_rotation(direction.into())
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Direction> for GlobalTransform {
         fn from(direction: Direction) -> Self {
             GlobalTransform::from_rotation(direction.into())
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Transform> for Rotation {
         fn from(transform: Transform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<GlobalTransform> for Rotation {
         fn from(transform: GlobalTransform) -> Self {
             transform.rotation.into()
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Rotation> for Transform {
         fn from(rotation: Rotation) -> Self {
             Transform::from_rotation(rotation.into())
         }
     }
 
+    #[cfg(feature = "std")]
     impl From<Rotation> for GlobalTransform {
         fn from(rotation: Rotation) -> Self {
             GlobalTransform::from_rotation(rotation.into())
         }
     }
 }
+
+/// Uniform random sampling of orientations, enabled by the `rand` feature
+#[cfg(feature = "rand")]
+mod random {
+    use super::float_math;
+    use super::{Direction, Rotation};
+    use core::f32::consts::TAU;
+    use glam::Vec2;
+    use rand::Rng;
+
+    impl Rotation {
+        /// Samples a [`Rotation`] uniformly from all 3600 possible values
+        ///
+        /// Requires the `rand` feature.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Rotation;
+        ///
+        /// let rotation = Rotation::random(&mut rand::thread_rng());
+        /// assert!(rotation.deci_degrees() < Rotation::FULL_CIRCLE);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn random(rng: &mut impl Rng) -> Rotation {
+            Rotation {
+                // The upper bound is exclusive, as 3600 deci-degrees is the same as 0
+                deci_degrees: rng.gen_range(0..Rotation::FULL_CIRCLE),
+            }
+        }
+    }
+
+    impl Direction {
+        /// Samples a [`Direction`] uniformly from the unit circle
+        ///
+        /// Unlike [`Rotation::random`], this is not limited to a precision of a tenth of a degree.
+        /// The result is never [`Direction::NEUTRAL`].
+        ///
+        /// Requires the `rand` feature.
+        ///
+        /// # Example
+        /// ```rust
+        /// use leafwing_2d::orientation::Direction;
+        ///
+        /// let direction = Direction::random(&mut rand::thread_rng());
+        /// assert!((direction.unit_vector().length() - 1.0).abs() < 1e-6);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn random(rng: &mut impl Rng) -> Direction {
+            let radians: f32 = rng.gen_range(0.0..TAU);
+
+            Direction {
+                unit_vector: Vec2::new(float_math::sin(radians), float_math::cos(radians)),
+            }
+        }
+    }
+}
+
+/// The floating point functions used by the orientation and position math, backed by `std`
+#[cfg(not(feature = "libm"))]
+pub(crate) mod float_math {
+    #[inline]
+    pub(crate) fn sin(x: f32) -> f32 {
+        x.sin()
+    }
+
+    #[inline]
+    pub(crate) fn cos(x: f32) -> f32 {
+        x.cos()
+    }
+
+    #[inline]
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        y.atan2(x)
+    }
+
+    #[inline]
+    pub(crate) fn rem_euclid(x: f32, modulus: f32) -> f32 {
+        x.rem_euclid(modulus)
+    }
+
+    #[inline]
+    pub(crate) fn round(x: f32) -> f32 {
+        x.round()
+    }
+}
+
+/// The floating point functions used by the orientation and position math, backed by the pure-Rust `libm`
+///
+/// These give identical results on every platform, at some cost to speed.
+#[cfg(feature = "libm")]
+pub(crate) mod float_math {
+    #[inline]
+    pub(crate) fn sin(x: f32) -> f32 {
+        libm::sinf(x)
+    }
+
+    #[inline]
+    pub(crate) fn cos(x: f32) -> f32 {
+        libm::cosf(x)
+    }
+
+    #[inline]
+    pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+        libm::atan2f(y, x)
+    }
+
+    #[inline]
+    pub(crate) fn rem_euclid(x: f32, modulus: f32) -> f32 {
+        let remainder = libm::fmodf(x, modulus);
+        if remainder < 0.0 {
+            remainder + libm::fabsf(modulus)
+        } else {
+            remainder
+        }
+    }
+
+    #[inline]
+    pub(crate) fn round(x: f32) -> f32 {
+        libm::roundf(x)
+    }
+}
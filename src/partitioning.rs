@@ -1,11 +1,43 @@
 //! Tools to partition [`Orientations`](Orientation) into discrete regions
+//!
+//! With the `serde` feature, the partitioning enums provided here are serialized as their variant names (such as `"NorthEast"`),
+//! and can also be deserialized from their compass abbreviations (such as `"NE"`).
 
 use crate::orientation::{Direction, Orientation, Rotation};
 use bevy_math::Vec2;
+pub use leafwing_2d_macros::DirectionParitioning;
+
+#[cfg(feature = "reflect")]
+use bevy_reflect::{FromReflect, Reflect, ReflectDeserialize};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// An exhaustive partitioning of the unit circle, snapping continuous directional input into one of a few possible options
 ///
 /// Only `partitions` should be manually defined when implementing this trait for new types.
+///
+/// For fieldless enums, `#[derive(DirectionParitioning)]` will generate [`DirectionParitioning::partitions`]
+/// and the required conversions into [`Rotation`], [`Direction`] and [`Vec2`] for you.
+/// Each variant must be annotated with the angle (in degrees, clockwise from midnight) that it corresponds to.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::partitioning::DirectionParitioning;
+///
+/// #[derive(DirectionParitioning, Clone, Copy, Debug, PartialEq)]
+/// enum Tripod {
+///     #[partition(degrees = 0.0)]
+///     Up,
+///     #[partition(degrees = 120.0)]
+///     DownRight,
+///     #[partition(degrees = 240.0)]
+///     DownLeft,
+/// }
+///
+/// assert_eq!(Tripod::partitions(), vec![Tripod::Up, Tripod::DownRight, Tripod::DownLeft]);
+/// assert_eq!(Tripod::snap(Rotation::from_degrees(100.0)), Tripod::DownRight);
+/// ```
 pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> + Copy {
     /// Returns the vector of possible partitions that can be snapped to
     #[must_use]
@@ -38,6 +70,27 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
             .collect()
     }
 
+    /// Returns a vector of each partition, paired with the [`Rotation`] at its center
+    ///
+    /// This is in the same order as [`DirectionParitioning::partitions`],
+    /// and is convenient for building lookup tables, UI wheels and debug overlays.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::partitioning::{CardinalQuadrant, DirectionParitioning};
+    ///
+    /// let pairs = CardinalQuadrant::partitions_with_rotations();
+    /// assert_eq!(pairs[1], (CardinalQuadrant::East, Rotation::EAST));
+    /// ```
+    #[must_use]
+    fn partitions_with_rotations() -> Vec<(Self, Rotation)> {
+        Self::partitions()
+            .iter()
+            .map(|&partition| (partition, partition.into()))
+            .collect()
+    }
+
     /// Snaps to the nearest partition
     #[must_use]
     fn snap(rotationlike: impl Into<Rotation>) -> Self {
@@ -60,6 +113,85 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
             .0
     }
 
+    /// Returns how far `rotationlike` is from the nearest partition
+    ///
+    /// This is the distance that [`DirectionParitioning::snap`] minimizes,
+    /// and is useful for implementing custom deadzones around each partition.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::partitioning::{CardinalQuadrant, DirectionParitioning};
+    ///
+    /// assert_eq!(CardinalQuadrant::distance_to_nearest(Rotation::EAST), Rotation::NORTH);
+    /// assert_eq!(
+    ///     CardinalQuadrant::distance_to_nearest(Rotation::from_degrees(350.0)),
+    ///     Rotation::from_degrees(10.0)
+    /// );
+    /// ```
+    #[must_use]
+    fn distance_to_nearest(rotationlike: impl Into<Rotation>) -> Rotation {
+        let rotation = rotationlike.into();
+
+        Self::partitions()
+            .iter()
+            .map(|&partition| rotation.distance(partition.into()))
+            .min_by_key(|distance| distance.deci_degrees())
+            .expect(
+                "At least one element must be returned by `DirectionPartitioning::partitions()`",
+            )
+    }
+
+    /// Finds the partition that `rotationlike` has most recently passed when turning clockwise,
+    /// and how far it is along the arc towards the next partition
+    ///
+    /// The returned fraction lies in `[0.0, 1.0)`: it is 0.0 when `rotationlike` lies exactly on the partition,
+    /// and approaches 1.0 as it approaches the next partition in the clockwise direction.
+    /// This is useful for cross-fading between directional sprites.
+    ///
+    /// Unlike [`DirectionParitioning::snap`], the partition returned is not always the nearest one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::partitioning::{CardinalQuadrant, DirectionParitioning};
+    ///
+    /// let (partition, fraction) = CardinalQuadrant::snap_with_fraction(Rotation::from_degrees(135.0));
+    /// assert_eq!(partition, CardinalQuadrant::East);
+    /// assert_eq!(fraction, 0.5);
+    /// ```
+    #[must_use]
+    fn snap_with_fraction(rotationlike: impl Into<Rotation>) -> (Self, f32) {
+        let rotation = rotationlike.into();
+        let partitions = Self::partitions();
+
+        // The partition with the shortest clockwise arc to `rotation` is the one it has most recently passed
+        let (previous, offset) = partitions
+            .iter()
+            .map(|&partition| {
+                let partition_rotation: Rotation = partition.into();
+                (partition, (rotation - partition_rotation).deci_degrees())
+            })
+            .min_by_key(|&(_, offset)| offset)
+            .expect(
+                "At least one element must be returned by `DirectionPartitioning::partitions()`",
+            );
+
+        // The clockwise arc from the previous partition to the next distinct partition
+        let previous_rotation: Rotation = previous.into();
+        let arc = partitions
+            .iter()
+            .map(|&partition| {
+                let partition_rotation: Rotation = partition.into();
+                (partition_rotation - previous_rotation).deci_degrees()
+            })
+            .filter(|&arc| arc != 0)
+            .min()
+            .unwrap_or(Rotation::FULL_CIRCLE);
+
+        (previous, offset as f32 / arc as f32)
+    }
+
     /// Snaps a [`Rotation`] to the nearest matching discrete [`Rotation`]
     #[must_use]
     fn snap_rotation(rotation: Rotation) -> Rotation {
@@ -72,6 +204,50 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
         Self::snap_rotation(direction.into()).into()
     }
 
+    /// Snaps a [`Direction`] to the nearest partition
+    ///
+    /// Returns `None` for [`Direction::NEUTRAL`], which does not point towards any partition.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Direction;
+    /// use leafwing_2d::partitioning::{CardinalOctant, DirectionParitioning};
+    ///
+    /// assert_eq!(CardinalOctant::from_direction(Direction::SOUTHWEST), Some(CardinalOctant::SouthWest));
+    /// assert_eq!(CardinalOctant::from_direction(Direction::NEUTRAL), None);
+    /// ```
+    #[must_use]
+    fn from_direction(direction: Direction) -> Option<Self> {
+        if direction.is_neutral() {
+            None
+        } else {
+            Some(Self::snap(direction))
+        }
+    }
+
+    /// Snaps the raw position of an analog stick to the nearest partition, ignoring small deflections
+    ///
+    /// A radial deadzone is applied first: sticks whose length is less than or equal to `deadzone` return `None`,
+    /// as do sticks with no length or non-finite axes.
+    /// Otherwise, the stick's angle is snapped to the nearest partition, regardless of its magnitude.
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::math::Vec2;
+    /// use leafwing_2d::partitioning::{CardinalQuadrant, DirectionParitioning};
+    ///
+    /// assert_eq!(CardinalQuadrant::from_stick(Vec2::new(0.05, -0.05), 0.2), None);
+    /// assert_eq!(CardinalQuadrant::from_stick(Vec2::new(0.2, -0.9), 0.2), Some(CardinalQuadrant::South));
+    /// ```
+    #[must_use]
+    fn from_stick(stick: Vec2, deadzone: f32) -> Option<Self> {
+        if !stick.is_finite() || stick.length() <= deadzone {
+            return None;
+        }
+
+        Rotation::from_vec2(stick).ok().map(Self::snap)
+    }
+
     /// Snaps a [`Vec2`] to the nearest matching discrete [`Direction`], preserving the magnitude
     ///
     /// If `vec2` has zero length, `Vec2::ZERO` will be returned instead.
@@ -87,14 +263,24 @@ pub trait DirectionParitioning: Into<Rotation> + Into<Direction> + Into<Vec2> +
 
 /// A 4-way [`DirectionParitioning`], corresponding to the four cardinal directions
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CardinalQuadrant {
     /// Up
+    #[cfg_attr(feature = "serde", serde(alias = "N"))]
     North,
     /// Right
+    #[cfg_attr(feature = "serde", serde(alias = "E"))]
     East,
     /// Down
+    #[cfg_attr(feature = "serde", serde(alias = "S"))]
     South,
     /// Left
+    #[cfg_attr(feature = "serde", serde(alias = "W"))]
     West,
 }
 
@@ -108,14 +294,24 @@ impl DirectionParitioning for CardinalQuadrant {
 
 /// A 4-way [`DirectionParitioning`], corresponding to the four cardinal directions offset by 45 degrees
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OffsetQuadrant {
     /// Up and right
+    #[cfg_attr(feature = "serde", serde(alias = "NE"))]
     NorthEast,
     /// Down and right
+    #[cfg_attr(feature = "serde", serde(alias = "SE"))]
     SouthEast,
     /// Down and left
+    #[cfg_attr(feature = "serde", serde(alias = "SW"))]
     SouthWest,
     /// Up and left
+    #[cfg_attr(feature = "serde", serde(alias = "NW"))]
     NorthWest,
 }
 
@@ -129,22 +325,36 @@ impl DirectionParitioning for OffsetQuadrant {
 
 /// A 8-way [`DirectionParitioning`], corresponding to the four cardinal directions and the intermediate values
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CardinalOctant {
     /// Up
+    #[cfg_attr(feature = "serde", serde(alias = "N"))]
     North,
     /// Up and right
+    #[cfg_attr(feature = "serde", serde(alias = "NE"))]
     NorthEast,
     /// Right
+    #[cfg_attr(feature = "serde", serde(alias = "E"))]
     East,
     /// Down and right
+    #[cfg_attr(feature = "serde", serde(alias = "SE"))]
     SouthEast,
     /// Down
+    #[cfg_attr(feature = "serde", serde(alias = "S"))]
     South,
     /// Down and left
+    #[cfg_attr(feature = "serde", serde(alias = "SW"))]
     SouthWest,
     /// Left
+    #[cfg_attr(feature = "serde", serde(alias = "W"))]
     West,
     /// Up and left
+    #[cfg_attr(feature = "serde", serde(alias = "NW"))]
     NorthWest,
 }
 
@@ -158,23 +368,120 @@ impl DirectionParitioning for CardinalOctant {
     }
 }
 
+/// A 16-way [`DirectionParitioning`], corresponding to the 16 points of a compass rose
+///
+/// This is useful for fine-grained directional input, such as 16-way movement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CardinalHexadecant {
+    /// Up
+    #[cfg_attr(feature = "serde", serde(alias = "N"))]
+    North,
+    /// Up and slightly right
+    #[cfg_attr(feature = "serde", serde(alias = "NNE"))]
+    NorthNorthEast,
+    /// Up and right
+    #[cfg_attr(feature = "serde", serde(alias = "NE"))]
+    NorthEast,
+    /// Right and slightly up
+    #[cfg_attr(feature = "serde", serde(alias = "ENE"))]
+    EastNorthEast,
+    /// Right
+    #[cfg_attr(feature = "serde", serde(alias = "E"))]
+    East,
+    /// Right and slightly down
+    #[cfg_attr(feature = "serde", serde(alias = "ESE"))]
+    EastSouthEast,
+    /// Down and right
+    #[cfg_attr(feature = "serde", serde(alias = "SE"))]
+    SouthEast,
+    /// Down and slightly right
+    #[cfg_attr(feature = "serde", serde(alias = "SSE"))]
+    SouthSouthEast,
+    /// Down
+    #[cfg_attr(feature = "serde", serde(alias = "S"))]
+    South,
+    /// Down and slightly left
+    #[cfg_attr(feature = "serde", serde(alias = "SSW"))]
+    SouthSouthWest,
+    /// Down and left
+    #[cfg_attr(feature = "serde", serde(alias = "SW"))]
+    SouthWest,
+    /// Left and slightly down
+    #[cfg_attr(feature = "serde", serde(alias = "WSW"))]
+    WestSouthWest,
+    /// Left
+    #[cfg_attr(feature = "serde", serde(alias = "W"))]
+    West,
+    /// Left and slightly up
+    #[cfg_attr(feature = "serde", serde(alias = "WNW"))]
+    WestNorthWest,
+    /// Up and left
+    #[cfg_attr(feature = "serde", serde(alias = "NW"))]
+    NorthWest,
+    /// Up and slightly left
+    #[cfg_attr(feature = "serde", serde(alias = "NNW"))]
+    NorthNorthWest,
+}
+
+impl DirectionParitioning for CardinalHexadecant {
+    fn partitions() -> Vec<Self> {
+        use CardinalHexadecant::*;
+
+        vec![
+            North,
+            NorthNorthEast,
+            NorthEast,
+            EastNorthEast,
+            East,
+            EastSouthEast,
+            SouthEast,
+            SouthSouthEast,
+            South,
+            SouthSouthWest,
+            SouthWest,
+            WestSouthWest,
+            West,
+            WestNorthWest,
+            NorthWest,
+            NorthNorthWest,
+        ]
+    }
+}
+
 /// A 6-way [`DirectionParitioning`], corresponding to the 6 directions of a tip-up hexagon
 ///
 /// For visualization purposes, these hexagons can be tiled in a row.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CardinalSextant {
     /// Up
+    #[cfg_attr(feature = "serde", serde(alias = "N"))]
     North,
     /// Up and right
+    #[cfg_attr(feature = "serde", serde(alias = "NE"))]
     NorthEast,
     /// Down and right
+    #[cfg_attr(feature = "serde", serde(alias = "SE"))]
     SouthEast,
     /// Down
+    #[cfg_attr(feature = "serde", serde(alias = "S"))]
     South,
     /// Down and left
+    #[cfg_attr(feature = "serde", serde(alias = "SW"))]
     SouthWest,
     /// Up and left
+    #[cfg_attr(feature = "serde", serde(alias = "NW"))]
     NorthWest,
 }
 
@@ -190,19 +497,30 @@ impl DirectionParitioning for CardinalSextant {
 ///
 /// For visualization purposes, these hexagons can be tiled in a column.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect, FromReflect),
+    reflect_value(PartialEq, Serialize, Deserialize)
+)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum OffsetSextant {
     /// Up and right
+    #[cfg_attr(feature = "serde", serde(alias = "NE"))]
     NorthEast,
     /// Right
+    #[cfg_attr(feature = "serde", serde(alias = "E"))]
     East,
     /// Down and right
+    #[cfg_attr(feature = "serde", serde(alias = "SE"))]
     SouthEast,
     /// Down and left,
+    #[cfg_attr(feature = "serde", serde(alias = "SW"))]
     SouthWest,
     /// Left
+    #[cfg_attr(feature = "serde", serde(alias = "W"))]
     West,
     /// Up and left
+    #[cfg_attr(feature = "serde", serde(alias = "NW"))]
     NorthWest,
 }
 
@@ -214,6 +532,52 @@ impl DirectionParitioning for OffsetSextant {
     }
 }
 
+/// A lookup table of values keyed by [`Rotation`], such as the frames of a directional sprite sheet
+///
+/// Unlike a [`DirectionParitioning`], the entries do not need to be evenly spaced around the circle:
+/// [`DirectionalSprites::get`] simply returns the value whose rotation is nearest.
+///
+/// # Example
+/// ```rust
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::partitioning::DirectionalSprites;
+///
+/// let sprites = DirectionalSprites {
+///     entries: vec![
+///         (Rotation::from_degrees(0.0), "up"),
+///         (Rotation::from_degrees(100.0), "right"),
+///         (Rotation::from_degrees(200.0), "down"),
+///     ],
+/// };
+///
+/// assert_eq!(*sprites.get(Rotation::from_degrees(60.0)), "right");
+/// assert_eq!(*sprites.get(Rotation::from_degrees(290.0)), "up");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DirectionalSprites<T> {
+    /// The values, each paired with the [`Rotation`] that it represents
+    pub entries: Vec<(Rotation, T)>,
+}
+
+impl<T> DirectionalSprites<T> {
+    /// Returns the value whose [`Rotation`] is angularly nearest to `rotation`
+    ///
+    /// Distances are measured with [`Orientation::distance`], and so wrap around the circle.
+    /// If several entries are equally near, the first of them is returned.
+    ///
+    /// # Panics
+    /// Panics if `entries` is empty.
+    #[must_use]
+    pub fn get(&self, rotation: Rotation) -> &T {
+        &self
+            .entries
+            .iter()
+            .min_by_key(|(entry_rotation, _)| rotation.distance(*entry_rotation).deci_degrees())
+            .expect("At least one element must be stored in `DirectionalSprites::entries`")
+            .1
+    }
+}
+
 mod parition_conversions {
     use super::*;
 
@@ -299,6 +663,44 @@ mod parition_conversions {
         }
     }
 
+    // Hexadecant
+    impl From<CardinalHexadecant> for Rotation {
+        fn from(hexadecant: CardinalHexadecant) -> Rotation {
+            match hexadecant {
+                CardinalHexadecant::North => Rotation::NORTH,
+                CardinalHexadecant::NorthNorthEast => Rotation::NORTH_NORTHEAST,
+                CardinalHexadecant::NorthEast => Rotation::NORTHEAST,
+                CardinalHexadecant::EastNorthEast => Rotation::EAST_NORTHEAST,
+                CardinalHexadecant::East => Rotation::EAST,
+                CardinalHexadecant::EastSouthEast => Rotation::EAST_SOUTHEAST,
+                CardinalHexadecant::SouthEast => Rotation::SOUTHEAST,
+                CardinalHexadecant::SouthSouthEast => Rotation::SOUTH_SOUTHEAST,
+                CardinalHexadecant::South => Rotation::SOUTH,
+                CardinalHexadecant::SouthSouthWest => Rotation::SOUTH_SOUTHWEST,
+                CardinalHexadecant::SouthWest => Rotation::SOUTHWEST,
+                CardinalHexadecant::WestSouthWest => Rotation::WEST_SOUTHWEST,
+                CardinalHexadecant::West => Rotation::WEST,
+                CardinalHexadecant::WestNorthWest => Rotation::WEST_NORTHWEST,
+                CardinalHexadecant::NorthWest => Rotation::NORTHWEST,
+                CardinalHexadecant::NorthNorthWest => Rotation::NORTH_NORTHWEST,
+            }
+        }
+    }
+
+    impl From<CardinalHexadecant> for Direction {
+        fn from(hexadecant: CardinalHexadecant) -> Direction {
+            let rotation: Rotation = hexadecant.into();
+            rotation.into()
+        }
+    }
+
+    impl From<CardinalHexadecant> for Vec2 {
+        fn from(hexadecant: CardinalHexadecant) -> Vec2 {
+            let rotation: Rotation = hexadecant.into();
+            rotation.into()
+        }
+    }
+
     // Sextant
     impl From<CardinalSextant> for Rotation {
         fn from(sextant: CardinalSextant) -> Rotation {
@@ -3,15 +3,19 @@
 use crate::continuous::F32;
 use crate::coordinate::Coordinate;
 use crate::kinematics::systems::{angular_kinematics, linear_kinematics};
-use crate::orientation::{Direction, Rotation};
+use crate::orientation::{Direction, Orientation, Rotation};
 use crate::position::Position;
+use crate::spatial::{update_spatial_hash, SpatialHash};
+use crate::targeting::face_target;
+use crate::transform::{Plane, TwoDTransform};
 
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_ecs::schedule::ShouldRun;
 use bevy_ecs::system::Resource;
-use bevy_math::Quat;
+use bevy_math::Vec2;
 use bevy_transform::components::Transform;
+use bevy_transform::TransformSystem;
 use core::fmt::Debug;
 use core::hash::Hash;
 use core::marker::PhantomData;
@@ -20,7 +24,8 @@ use core::marker::PhantomData;
 ///
 /// The type paramter `C` is the coordinate type used in [`Position`].
 /// [`Transform`] can be modified directly, but if both the [`Transform`]
-/// and its 2D analogue have been changed, the 2D version will take priority.
+/// and its 2D analogue have been changed, the [`SyncPolicy`] determines which one takes priority.
+/// By default, the 2D version will take priority.
 /// Similary, [`Rotation`] takes priority over [`Direction`].
 ///
 /// System labels are stored in [`TwoDSystem`], which describes the working of this plugin in more depth.
@@ -30,7 +35,8 @@ use core::marker::PhantomData;
 /// ```rust
 /// use bevy::prelude::*;
 /// use leafwing_2d::prelude::*;
-/// use leafwing_2d::plugin::GameState;
+/// use leafwing_2d::plugin::{GameState, SyncPolicy};
+/// use leafwing_2d::transform::Plane;
 /// use leafwing_2d::discrete::FlatHex;
 /// use core::marker::PhantomData;
 ///
@@ -40,6 +46,9 @@ use core::marker::PhantomData;
 ///     .add_plugin(TwoDPlugin {
 ///       kinematics: false,
 ///       kinematics_state: None,
+///       sync_policy: SyncPolicy::TwoDAuthoritative,
+///       plane: Plane::Xy,
+///       spatial_hash: None,
 ///       stage: CoreStage::PostUpdate,
 ///       // Hexagons are the bestagons
 ///       coordinate_type: PhantomData::<FlatHex>::default(),
@@ -63,6 +72,24 @@ pub struct TwoDPlugin<
     ///
     /// Default: [`None`]
     pub kinematics_state: Option<UserState>,
+    /// Should the 2D components or the [`Transform`] win when both have been changed?
+    ///
+    /// This is stored as a resource, and can be modified at run time.
+    ///
+    /// Default: [`SyncPolicy::TwoDAuthoritative`]
+    pub sync_policy: SyncPolicy,
+    /// Which plane of the [`Transform`] do the 2D components map onto?
+    ///
+    /// This is stored as a resource, and can be modified at run time.
+    ///
+    /// Default: [`Plane::Xy`]
+    pub plane: Plane,
+    /// The cell size of the [`SpatialHash<C>`] to maintain, if any
+    ///
+    /// If `Some`, a [`SpatialHash<C>`] resource is inserted and rebuilt every frame by [`update_spatial_hash::<C>`].
+    ///
+    /// Default: [`None`]
+    pub spatial_hash: Option<f32>,
     /// Which stage should these systems run in?
     ///
     /// Default: [`CoreStage::PostUpdate`]
@@ -78,6 +105,9 @@ impl Default for TwoDPlugin<F32, GameState, CoreStage> {
         Self {
             kinematics: true,
             kinematics_state: None,
+            sync_policy: SyncPolicy::default(),
+            plane: Plane::default(),
+            spatial_hash: None,
             stage: CoreStage::PostUpdate,
             coordinate_type: PhantomData::<F32>::default(),
         }
@@ -93,29 +123,102 @@ pub enum GameState {
     Paused,
 }
 
+/// Controls which data is used when both a [`Transform`] and its 2D analogue have changed
+///
+/// Inserted as a resource by [`TwoDPlugin`], and read by [`sync_transform_with_2d`].
+/// If only one side has changed, it will always be copied onto the other.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum SyncPolicy {
+    /// [`Position`], [`Rotation`] and [`Direction`] are copied onto the [`Transform`]
+    ///
+    /// This is the default behavior, and is appropriate when your game logic works in 2D.
+    #[default]
+    TwoDAuthoritative,
+    /// The [`Transform`] is copied onto [`Position`], [`Rotation`] and [`Direction`]
+    ///
+    /// Use this when the [`Transform`] is driven by another source of truth, such as a physics engine.
+    TransformAuthoritative,
+}
+
+impl SyncPolicy {
+    /// Should the 2D data be copied onto the [`Transform`] (`Some(true)`), or the other way around (`Some(false)`)?
+    ///
+    /// Returns `None` if neither side has changed.
+    fn two_d_wins(self, two_d_changed: bool, transform_changed: bool) -> Option<bool> {
+        match (two_d_changed, transform_changed) {
+            (false, false) => None,
+            (true, false) => Some(true),
+            (false, true) => Some(false),
+            (true, true) => Some(self == SyncPolicy::TwoDAuthoritative),
+        }
+    }
+}
+
 /// [`SystemLabel`] for [`TwoDPlugin`]
 ///
 /// These labels are executed in sequence.
 #[derive(SystemLabel, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TwoDSystem {
+    /// Initializes the 2D components of newly spawned entities from their [`Transform`]
+    ///
+    /// This prevents entities authored with a [`Transform`] (such as those loaded from scenes)
+    /// from snapping to the default orientation and position for a frame.
+    ///
+    /// Contains [`initialize_2d_from_transform::<C>`].
+    InitializeFromTransform,
+    /// The place for gameplay systems that write to [`Position`], [`Rotation`] or [`Direction`]
+    ///
+    /// Label your own movement and integration systems with this,
+    /// and they will run after the 2D components of new entities are initialized,
+    /// but before any of the other systems in this plugin.
+    /// Their changes will then be reflected in the [`Transform`] in the same frame.
+    ///
+    /// This plugin does not add any systems of its own to this label,
+    /// other than an empty placeholder that ensures that the label always exists.
+    Integrate,
     /// Applies acceleration and velocity
     ///
     /// Contains [`linear_kinematics::<C>`] and [`angular_kinematics`].
     /// Disable these by setting the `kinematics` field of [`TwoDPlugin`].
     Kinematics,
+    /// Turns entities with a [`FaceTarget`](crate::targeting::FaceTarget) component towards their target
+    ///
+    /// Contains [`face_target::<C>`].
+    FaceTarget,
+    /// Snaps the facing of entities with a [`SnapTo`](crate::snapping::SnapTo) component to a partition
+    ///
+    /// Contains [`snap_to_partition::<P>`](crate::snapping::snap_to_partition) for each [`SnapToPlugin`](crate::snapping::SnapToPlugin) added.
+    /// No systems are added to this label by [`TwoDPlugin`].
+    SnapToPartition,
     /// Synchronizes the [`Direction`] and [`Rotation`] of all entities
     ///
     /// If [`Direction`] and [`Rotation`] are desynced, whichever one was changed will be used and the other will be made consistent.
     /// If both were changed, [`Rotation`] will be prioritized
     ///
+    /// [`Direction::NEUTRAL`] is never overwritten, and never propagated:
+    /// entities with a neutral direction keep it until it is replaced explicitly.
+    ///
     /// Contains [`sync_direction_and_rotation`].
     SyncDirectionRotation,
     /// Synchronizes the [`Rotation`] and [`Position`] of each entity with its [`Transform`]
     ///
     /// Not all components are needed for this system to do its work.
+    /// Runs before Bevy's [`TransformSystem::TransformPropagate`], so the `GlobalTransform` is up to date in the same frame.
     ///
     /// Contains [`sync_transform_with_2d`].
     SyncTransform,
+    /// Rebuilds the [`SpatialHash<C>`] from the final [`Position`] of each entity
+    ///
+    /// Contains [`update_spatial_hash::<C>`].
+    /// Only added when the `spatial_hash` field of [`TwoDPlugin`] is set.
+    UpdateSpatialHash,
+    /// Reports entities whose [`Transform`] was changed after [`TwoDSystem::SyncTransform`]
+    ///
+    /// Runs in [`CoreStage::Last`], so writes made anywhere after the sync are caught, whatever their system order.
+    ///
+    /// This variant always exists, but only labels a system when the `desync_warnings` feature is enabled:
+    /// that system is `warn_on_transform_desync::<C>`.
+    DetectTransformDesyncs,
 }
 
 impl<
@@ -125,12 +228,42 @@ impl<
     > Plugin for TwoDPlugin<C, UserState, UserStage>
 {
     fn build(&self, app: &mut App) {
+        app.insert_resource(self.sync_policy);
+        app.insert_resource(self.plane);
+
+        #[cfg(feature = "reflect")]
+        {
+            use crate::partitioning::*;
+
+            app.register_type::<CardinalQuadrant>()
+                .register_type::<OffsetQuadrant>()
+                .register_type::<CardinalOctant>()
+                .register_type::<CardinalHexadecant>()
+                .register_type::<CardinalSextant>()
+                .register_type::<OffsetSextant>();
+        }
+
+        app.add_system_to_stage(
+            self.stage.clone(),
+            initialize_2d_from_transform::<C>
+                .label(TwoDSystem::InitializeFromTransform)
+                .before(TwoDSystem::Integrate),
+        );
+
+        // Bevy warns when systems are ordered against a label that no system has,
+        // so the plugin's own systems need something to wait for
+        app.add_system_to_stage(
+            self.stage.clone(),
+            integrate_placeholder.label(TwoDSystem::Integrate),
+        );
+
         if self.kinematics {
             let kinematics_systems = SystemSet::new()
                 .with_system(linear_kinematics::<C>)
                 .with_system(angular_kinematics)
                 .label(TwoDSystem::Kinematics)
-                .before(TwoDSystem::SyncDirectionRotation);
+                .after(TwoDSystem::Integrate)
+                .before(TwoDSystem::FaceTarget);
 
             // If a state has been provided
             // Only run this plugin's systems in the state variant provided
@@ -157,11 +290,94 @@ impl<
             }
         }
 
+        app.add_system_to_stage(
+            self.stage.clone(),
+            face_target::<C>
+                .label(TwoDSystem::FaceTarget)
+                .after(TwoDSystem::Integrate)
+                .before(TwoDSystem::SyncDirectionRotation),
+        );
+
         let sync_systems = SystemSet::new()
             .with_system(sync_direction_and_rotation.label(TwoDSystem::SyncDirectionRotation))
-            .with_system(sync_transform_with_2d::<C>.label(TwoDSystem::SyncTransform));
+            .with_system(
+                sync_transform_with_2d::<C>
+                    .label(TwoDSystem::SyncTransform)
+                    .after(TwoDSystem::SyncDirectionRotation),
+            )
+            // Propagation must see this frame's transforms, or the GlobalTransform lags a frame behind
+            .before(TransformSystem::TransformPropagate);
 
         app.add_system_set_to_stage(self.stage.clone(), sync_systems);
+
+        if let Some(cell_size) = self.spatial_hash {
+            app.insert_resource(SpatialHash::<C>::new(cell_size))
+                .add_system_to_stage(
+                    self.stage.clone(),
+                    update_spatial_hash::<C>
+                        .label(TwoDSystem::UpdateSpatialHash)
+                        .after(TwoDSystem::SyncTransform),
+                );
+        }
+
+        // Systems in the plugin's stage can write to the transform in any order,
+        // so the check waits until they have all finished
+        #[cfg(feature = "desync_warnings")]
+        app.init_resource::<TransformDesyncs>().add_system_to_stage(
+            CoreStage::Last,
+            warn_on_transform_desync::<C>.label(TwoDSystem::DetectTransformDesyncs),
+        );
+    }
+}
+
+/// An empty system, labelled with [`TwoDSystem::Integrate`] so that the label always exists
+fn integrate_placeholder() {}
+
+/// Copies the [`Transform`] of newly spawned entities onto their 2D components
+///
+/// When a [`Transform`] is added at the same time as the [`Rotation`], [`Direction`] or [`Position`],
+/// all of these components are considered changed, and so [`sync_transform_with_2d`] would normally
+/// overwrite the [`Transform`] with the default 2D values.
+/// Instead, any 2D component that was added alongside the [`Transform`] and still has its default value
+/// is initialized from the [`Transform`].
+///
+/// 2D components that were given a non-default value, or that were added before the [`Transform`], are left untouched,
+/// and are copied onto the [`Transform`] as usual.
+/// As a result, explicitly spawning an entity with the default value (such as [`Rotation::NORTH`])
+/// and a different [`Transform`] will keep the [`Transform`]'s value.
+pub fn initialize_2d_from_transform<C: Coordinate>(
+    plane: Res<Plane>,
+    mut query: Query<
+        (
+            &Transform,
+            Option<&mut Rotation>,
+            Option<&mut Direction>,
+            Option<&mut Position<C>>,
+        ),
+        Added<Transform>,
+    >,
+) {
+    for (transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut() {
+        // The transform, as it would be read by `sync_transform_with_2d`
+        let transform_2d = TwoDTransform::<C>::from_plane(transform, *plane);
+
+        if let Some(mut rotation) = maybe_rotation {
+            if rotation.is_added() && *rotation == Rotation::default() {
+                *rotation = transform_2d.rotation;
+            }
+        }
+
+        if let Some(mut direction) = maybe_direction {
+            if direction.is_added() && *direction == Direction::default() {
+                *direction = transform_2d.direction();
+            }
+        }
+
+        if let Some(mut position) = maybe_position {
+            if position.is_added() && *position == Position::default() {
+                *position = transform_2d.position;
+            }
+        }
     }
 }
 
@@ -169,20 +385,20 @@ impl<
 ///
 /// If [`Direction`] and [`Rotation`] are desynced, whichever one was changed will be used and the other will be made consistent.
 /// If both were changed, [`Rotation`] will be prioritized
+///
+/// A [`Direction::NEUTRAL`] direction represents a lack of facing, and so is left untouched:
+/// it will not be overwritten when [`Rotation`] changes, and setting it will not change the [`Rotation`].
 pub fn sync_direction_and_rotation(mut query: Query<(&mut Direction, &mut Rotation)>) {
     for (mut direction, mut rotation) in query.iter_mut() {
+        // Neutral directions must be replaced explicitly
+        if direction.is_neutral() {
+            continue;
+        }
+
         if rotation.is_changed() {
-            let new_direction: Direction = (*rotation).into();
-            // These checks are required to avoid triggering change detection pointlessly,
-            // which would create an infinite ping-pong effect
-            if *direction != new_direction {
-                *direction = new_direction;
-            }
+            set_if_changed(&mut direction, (*rotation).into());
         } else if direction.is_changed() {
-            let new_rotation = (*direction).into();
-            if *rotation != new_rotation {
-                *rotation = new_rotation;
-            }
+            set_if_changed(&mut rotation, (*direction).into());
         }
     }
 }
@@ -190,12 +406,28 @@ pub fn sync_direction_and_rotation(mut query: Query<(&mut Direction, &mut Rotati
 /// Synchronizes the [`Rotation`], [`Direction`] and [`Position`] of each entity with its [`Transform`] and vice versa
 ///
 /// [`Transform`] can be modified directly, but if both the [`Transform`]
-/// and its 2D analogue have been changed, the 2D version will take priority.
+/// and its 2D analogue have been changed, the [`SyncPolicy`] resource determines which one takes priority.
 ///
-/// z-values of the [`Transform`] translation will not be modified.
+/// The [`Plane`] resource determines which axes of the [`Transform`] the 2D components map onto.
+/// The conversions are performed by [`TwoDTransform::from_plane`] and [`TwoDTransform::apply_to_plane`].
+/// The translation along the axis perpendicular to this plane will not be modified.
 /// Any off-axis rotation of the [`Transform`]'s rotation quaternion will be lost.
+/// A [`Direction::NEUTRAL`] direction is neither read from nor written to.
+///
+/// [`Position`] is converted into the `f32` translation through [`Into<f32>`],
+/// so coordinates with more precision than `f32` (such as [`F64`](crate::continuous::F64)) are rounded onto the [`Transform`].
+///
+/// Like the [`Transform`] they are synchronized with, the 2D components of a child entity are relative to its parent.
+/// A turret on a rotating hull that should keep "facing forward" can simply keep a [`Rotation`] of [`Rotation::NORTH`]:
+/// its world-space facing is composed by Bevy's transform propagation, and can be read from its `GlobalTransform`.
+///
+/// Entities where none of these components have changed are filtered out by the query itself, so no syncing work is done for them.
+/// Bevy still checks the change ticks of every matching entity, so the cost of this system grows with the number of static entities:
+/// see the `sync_transform` benchmark for a comparison with an unfiltered query.
 // FIXME: also sync `Scale`.
 pub fn sync_transform_with_2d<C: Coordinate>(
+    sync_policy: Res<SyncPolicy>,
+    plane: Res<Plane>,
     mut query: Query<
         (
             &mut Transform,
@@ -203,61 +435,236 @@ pub fn sync_transform_with_2d<C: Coordinate>(
             Option<&mut Direction>,
             Option<&mut Position<C>>,
         ),
-        Or<(With<Rotation>, With<Position<C>>)>,
+        (
+            Or<(With<Rotation>, With<Position<C>>)>,
+            Or<(
+                Changed<Transform>,
+                Changed<Rotation>,
+                Changed<Direction>,
+                Changed<Position<C>>,
+            )>,
+        ),
     >,
 ) {
     for (mut transform, maybe_rotation, maybe_direction, maybe_position) in query.iter_mut() {
+        // Read this before the transform is written to below,
+        // so that the sync's own writes are not mistaken for changes made by other systems
+        let transform_changed = transform.is_changed();
+
+        // The transform, as it would be read by `initialize_2d_from_transform`
+        let transform_2d = TwoDTransform::<C>::from_plane(&transform, *plane);
+        // The transform, with any 2D components that take priority copied over
+        let mut new_transform_2d = transform_2d;
+
         // Synchronize Rotation with Transform
         if let Some(mut rotation) = maybe_rotation {
-            if rotation.is_changed() {
-                let new_quat: Quat = (*rotation).into();
-                if transform.rotation != new_quat {
-                    transform.rotation = new_quat;
-                }
-            } else if transform.is_changed() {
-                if let Ok(new_rotation) = transform.rotation.try_into() {
-                    if *rotation != new_rotation {
-                        *rotation = new_rotation;
-                    }
-                }
+            match sync_policy.two_d_wins(rotation.is_changed(), transform_changed) {
+                Some(true) => new_transform_2d.rotation = *rotation,
+                Some(false) => set_if_changed(&mut rotation, transform_2d.rotation),
+                None => (),
             }
         }
 
         // Synchronize Direction with Transform
-        if let Some(mut direction) = maybe_direction {
-            if direction.is_changed() {
-                let new_quat = (*direction).into();
-                if transform.rotation != new_quat {
-                    transform.rotation = new_quat;
-                }
-            } else if transform.is_changed() && *direction != transform.rotation.into() {
-                *direction = transform.rotation.into();
+        // Neutral directions have no facing to synchronize
+        if let Some(mut direction) = maybe_direction.filter(|direction| !direction.is_neutral()) {
+            match sync_policy.two_d_wins(direction.is_changed(), transform_changed) {
+                Some(true) => new_transform_2d.rotation = (*direction).into(),
+                Some(false) => set_if_changed(&mut direction, transform_2d.direction()),
+                None => (),
             }
         }
 
         // Synchronize Position with Transform
         if let Some(mut position) = maybe_position {
-            if position.is_changed() {
-                let new_x: f32 = position.x.into();
-                if transform.translation.x != new_x {
-                    transform.translation.x = new_x;
-                }
-
-                let new_y: f32 = position.y.into();
-                if transform.translation.y != new_y {
-                    transform.translation.y = new_y;
-                }
-            } else if transform.is_changed() {
-                let new_x = C::from(transform.translation.x);
-                if position.x != new_x {
-                    position.x = new_x;
-                }
-
-                let new_y = C::from(transform.translation.y);
-                if position.y != new_y {
-                    position.y = new_y;
-                }
+            match sync_policy.two_d_wins(position.is_changed(), transform_changed) {
+                Some(true) => new_transform_2d.position = *position,
+                Some(false) => set_if_changed(&mut position, transform_2d.position),
+                None => (),
             }
         }
+
+        if new_transform_2d != transform_2d {
+            let mut new_transform = *transform;
+            new_transform_2d.apply_to_plane(&mut new_transform, *plane);
+            set_if_changed(&mut transform, new_transform);
+        }
+    }
+}
+
+/// Overwrites `target` with `new`, but only if they differ
+///
+/// Writing through a [`Mut`] always marks the component as changed, even if the value is the same.
+/// The sync systems react to changes made by each other, so pointless writes would
+/// create an infinite ping-pong effect, with each system re-triggering the others every frame.
+pub(crate) fn set_if_changed<T: PartialEq>(target: &mut Mut<T>, new: T) {
+    if **target != new {
+        **target = new;
+    }
+}
+
+/// Reports each entity whose [`Transform`] disagrees with its 2D components, without modifying anything
+///
+/// This checks the same entities and components as [`sync_transform_with_2d`],
+/// and is intended for catching ordering bugs in tests and debug builds,
+/// such as a system that writes to the [`Transform`] after it has been synchronized.
+///
+/// An entity is reported if its [`Rotation`] or [`Direction`] is more than `max_rotation_error` away from the facing of its [`Transform`],
+/// or if either axis of its [`Position`] differs from the [`Transform`] translation by more than `max_position_error`.
+/// As in [`sync_transform_with_2d`], [`Direction::NEUTRAL`] is ignored.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::plugin::transform_desyncs;
+///
+/// let mut world = World::new();
+/// let entity = world
+///     .spawn()
+///     .insert(Position::<F32>::new(1.0, 2.0))
+///     .insert(Transform::from_xyz(1.0, 2.0, 0.0))
+///     .id();
+///
+/// assert!(transform_desyncs::<F32>(&mut world, Rotation::new(1), 0.01).is_empty());
+///
+/// world.get_mut::<Transform>(entity).unwrap().translation.x = 3.0;
+/// assert_eq!(transform_desyncs::<F32>(&mut world, Rotation::new(1), 0.01), vec![entity]);
+/// ```
+#[must_use]
+pub fn transform_desyncs<C: Coordinate>(
+    world: &mut World,
+    max_rotation_error: Rotation,
+    max_position_error: f32,
+) -> Vec<Entity> {
+    let plane = world.get_resource::<Plane>().copied().unwrap_or_default();
+    let mut query_state = world.query_filtered::<(
+        Entity,
+        &Transform,
+        Option<&Rotation>,
+        Option<&Direction>,
+        Option<&Position<C>>,
+    ), Or<(With<Rotation>, With<Position<C>>)>>();
+
+    query_state
+        .iter(world)
+        .filter(
+            |(_, transform, maybe_rotation, maybe_direction, maybe_position)| {
+                is_desynced(
+                    transform,
+                    *maybe_rotation,
+                    *maybe_direction,
+                    *maybe_position,
+                    plane,
+                    max_rotation_error,
+                    max_position_error,
+                )
+            },
+        )
+        .map(|(entity, ..)| entity)
+        .collect()
+}
+
+/// Does the [`Transform`] disagree with any of the supplied 2D components, by more than the supplied tolerances?
+///
+/// Shared by [`transform_desyncs`] and [`warn_on_transform_desync`].
+fn is_desynced<C: Coordinate>(
+    transform: &Transform,
+    maybe_rotation: Option<&Rotation>,
+    maybe_direction: Option<&Direction>,
+    maybe_position: Option<&Position<C>>,
+    plane: Plane,
+    max_rotation_error: Rotation,
+    max_position_error: f32,
+) -> bool {
+    // The transform, as it would be read by `sync_transform_with_2d`
+    let transform_2d = TwoDTransform::<C>::from_plane(transform, plane);
+
+    let rotation_desynced = maybe_rotation
+        .is_some_and(|&rotation| rotation.distance(transform_2d.rotation) > max_rotation_error);
+
+    let direction_desynced = maybe_direction
+        .filter(|direction| !direction.is_neutral())
+        .is_some_and(|&direction| {
+            Rotation::from(direction).distance(transform_2d.rotation) > max_rotation_error
+        });
+
+    let position_desynced = maybe_position.is_some_and(|&position| {
+        let offset = transform_2d.position.offset_to(position);
+        offset.x.abs() > max_position_error || offset.y.abs() > max_position_error
+    });
+
+    rotation_desynced || direction_desynced || position_desynced
+}
+
+/// The entities whose [`Transform`] was changed after it was synchronized during the most recent frame
+///
+/// Inserted as a resource by [`TwoDPlugin`] when the `desync_warnings` feature is enabled,
+/// and overwritten every frame by [`warn_on_transform_desync::<C>`].
+#[cfg(feature = "desync_warnings")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransformDesyncs {
+    /// The desynchronized entities, in no particular order
+    pub entities: Vec<Entity>,
+}
+
+/// Warns about each entity whose [`Transform`] was changed after [`TwoDSystem::SyncTransform`] ran
+///
+/// Writing to the [`Transform`] after it has been synchronized leaves the [`Position`], [`Rotation`] and [`Direction`] stale,
+/// and the write will be overwritten (or copied back) unpredictably on the next frame.
+/// This is almost always caused by a system that should be ordered before [`TwoDSystem::SyncTransform`].
+///
+/// Only entities whose [`Transform`] changed this frame are checked.
+/// Newly added [`Transform`]s are skipped, as they are initialized from on the next frame by [`initialize_2d_from_transform::<C>`].
+/// Each offending entity is logged as a warning, and recorded in the [`TransformDesyncs`] resource.
+///
+/// Added to [`CoreStage::Last`] when the `desync_warnings` feature is enabled, labelled with [`TwoDSystem::DetectTransformDesyncs`].
+/// Running in its own stage means that every write made after the sync in the `stage` of [`TwoDPlugin`] and in later stages is detected,
+/// even from systems that are not ordered against the plugin's systems at all.
+/// As a result, the `stage` of [`TwoDPlugin`] must come before [`CoreStage::Last`] when this feature is enabled.
+/// Writes made in [`CoreStage::Last`] itself are only detected if they are ordered before [`TwoDSystem::DetectTransformDesyncs`].
+#[cfg(feature = "desync_warnings")]
+pub fn warn_on_transform_desync<C: Coordinate>(
+    plane: Res<Plane>,
+    mut desyncs: ResMut<TransformDesyncs>,
+    query: Query<
+        (
+            Entity,
+            &Transform,
+            ChangeTrackers<Transform>,
+            Option<&Rotation>,
+            Option<&Direction>,
+            Option<&Position<C>>,
+        ),
+        (
+            Changed<Transform>,
+            Or<(With<Rotation>, With<Direction>, With<Position<C>>)>,
+        ),
+    >,
+) {
+    desyncs.entities.clear();
+
+    for (entity, transform, transform_tracker, maybe_rotation, maybe_direction, maybe_position) in
+        query.iter()
+    {
+        if transform_tracker.is_added() {
+            continue;
+        }
+
+        // Matches the tolerance of `Orientation::assert_approx_eq`
+        if is_desynced(
+            transform,
+            maybe_rotation,
+            maybe_direction,
+            maybe_position,
+            *plane,
+            Rotation::new(2),
+            0.01,
+        ) {
+            bevy_utils::tracing::warn!(
+                "The Transform of {entity:?} was changed after TwoDSystem::SyncTransform, and no longer matches its 2D components. Order the system that writes to it before TwoDSystem::SyncTransform."
+            );
+            desyncs.entities.push(entity);
+        }
     }
 }
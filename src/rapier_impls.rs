@@ -0,0 +1,70 @@
+//! Conversions to and from the rotations used by [`bevy_rapier2d`], enabled by the `rapier` feature
+//!
+//! Rapier measures angles in radians, counterclockwise from east (+x),
+//! while [`Rotation`] measures tenths of a degree, clockwise from north (+y).
+//! These conversions translate between the two conventions,
+//! so that a rigid body and its [`Rotation`] always face the same way.
+
+use crate::orientation::Rotation;
+use bevy_rapier2d::rapier::math::Real;
+use bevy_rapier2d::rapier::na::UnitComplex;
+
+impl Rotation {
+    /// Construct a [`Rotation`] from one of rapier's angles, in radians measured counterclockwise from east (+x)
+    ///
+    /// This is the value returned by `UnitComplex::angle` on a rigid body's rotation.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::{Orientation, Rotation};
+    /// use core::f32::consts::FRAC_PI_2;
+    ///
+    /// Rotation::from_rapier_angle(0.0).assert_approx_eq(Rotation::EAST);
+    /// Rotation::from_rapier_angle(FRAC_PI_2).assert_approx_eq(Rotation::NORTH);
+    /// Rotation::from_rapier_angle(-FRAC_PI_2).assert_approx_eq(Rotation::SOUTH);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_rapier_angle(angle: Real) -> Rotation {
+        Rotation::from_math_radians(angle)
+    }
+
+    /// Converts this rotation into one of rapier's angles, in radians measured counterclockwise from east (+x)
+    ///
+    /// Like [`UnitComplex::angle`], the returned value is always within `(-PI, PI]`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use leafwing_2d::orientation::Rotation;
+    /// use core::f32::consts::{FRAC_PI_2, PI};
+    ///
+    /// assert_eq!(Rotation::EAST.into_rapier_angle(), 0.0);
+    /// assert!((Rotation::NORTH.into_rapier_angle() - FRAC_PI_2).abs() < 0.001);
+    /// assert!((Rotation::WEST.into_rapier_angle() - PI).abs() < 0.001);
+    /// assert!((Rotation::SOUTH.into_rapier_angle() + FRAC_PI_2).abs() < 0.001);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn into_rapier_angle(self) -> Real {
+        use core::f32::consts::{PI, TAU};
+
+        let radians = self.into_math_radians();
+        if radians > PI {
+            radians - TAU
+        } else {
+            radians
+        }
+    }
+}
+
+impl From<Rotation> for UnitComplex<Real> {
+    fn from(rotation: Rotation) -> UnitComplex<Real> {
+        UnitComplex::new(rotation.into_rapier_angle())
+    }
+}
+
+impl From<UnitComplex<Real>> for Rotation {
+    fn from(unit_complex: UnitComplex<Real>) -> Rotation {
+        Rotation::from_rapier_angle(unit_complex.angle())
+    }
+}
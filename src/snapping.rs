@@ -0,0 +1,125 @@
+//! Tools for snapping the facing of entities to a discrete set of directions
+
+use crate::orientation::{Direction, Rotation};
+use crate::partitioning::DirectionParitioning;
+use crate::plugin::{set_if_changed, TwoDSystem};
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use core::marker::PhantomData;
+
+/// Snaps the [`Rotation`] and [`Direction`] of this entity to the nearest partition of `P` every frame
+///
+/// This is useful for entities that can only face a few directions, such as 8-directional sprites.
+/// The snapping is performed by [`snap_to_partition::<P>`], which is added by [`SnapToPlugin<P>`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::snapping::{SnapTo, SnapToPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugin(TwoDPlugin {
+///     kinematics: false,
+///     ..Default::default()
+/// })
+/// .add_plugin(SnapToPlugin::<CardinalQuadrant>::default());
+///
+/// let entity = app
+///     .world
+///     .spawn()
+///     .insert_bundle(TwoDBundle::<F32>::default())
+///     .insert(Rotation::from_degrees(100.0))
+///     .insert(SnapTo::<CardinalQuadrant>::default())
+///     .id();
+///
+/// app.update();
+///
+/// assert_eq!(*app.world.get::<Rotation>(entity).unwrap(), Rotation::EAST);
+/// ```
+#[derive(Component, Debug, PartialEq, Eq)]
+pub struct SnapTo<P: DirectionParitioning + Send + Sync + 'static> {
+    partitioning: PhantomData<P>,
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static> Default for SnapTo<P> {
+    fn default() -> Self {
+        Self {
+            partitioning: PhantomData,
+        }
+    }
+}
+
+// Implemented manually, as deriving would require `P: Clone`
+impl<P: DirectionParitioning + Send + Sync + 'static> Clone for SnapTo<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static> Copy for SnapTo<P> {}
+
+/// Snaps the [`Rotation`] and [`Direction`] of each entity with a [`SnapTo<P>`] component to the nearest partition of `P`
+///
+/// [`Direction::NEUTRAL`] has no facing, and so is left untouched.
+/// Components that are already snapped are not modified, and so will not trigger change detection.
+pub fn snap_to_partition<P: DirectionParitioning + Send + Sync + 'static>(
+    mut query: Query<(Option<&mut Rotation>, Option<&mut Direction>), With<SnapTo<P>>>,
+) {
+    for (maybe_rotation, maybe_direction) in query.iter_mut() {
+        if let Some(mut rotation) = maybe_rotation {
+            let snapped = P::snap_rotation(*rotation);
+            set_if_changed(&mut rotation, snapped);
+        }
+
+        if let Some(mut direction) = maybe_direction {
+            if !direction.is_neutral() {
+                let snapped = P::snap_direction(*direction);
+                set_if_changed(&mut direction, snapped);
+            }
+        }
+    }
+}
+
+/// Adds [`snap_to_partition::<P>`], snapping entities with a [`SnapTo<P>`] component
+///
+/// Add one copy of this plugin for each partitioning type that you use with [`SnapTo`].
+/// The system is labelled with [`TwoDSystem::SnapToPartition`], and so runs after [`TwoDSystem::FaceTarget`]
+/// but before the [`Transform`](bevy_transform::components::Transform) is synchronized.
+/// The `stage` should match the stage used by [`TwoDPlugin`](crate::plugin::TwoDPlugin).
+#[derive(Debug)]
+pub struct SnapToPlugin<
+    P: DirectionParitioning + Send + Sync + 'static,
+    UserStage: StageLabel = CoreStage,
+> {
+    /// Which stage should this system run in?
+    ///
+    /// Default: [`CoreStage::PostUpdate`]
+    pub stage: UserStage,
+    /// What [`DirectionParitioning`] should be snapped to?
+    pub partitioning: PhantomData<P>,
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static> Default for SnapToPlugin<P, CoreStage> {
+    fn default() -> Self {
+        Self {
+            stage: CoreStage::PostUpdate,
+            partitioning: PhantomData,
+        }
+    }
+}
+
+impl<P: DirectionParitioning + Send + Sync + 'static, UserStage: StageLabel + Clone> Plugin
+    for SnapToPlugin<P, UserStage>
+{
+    fn build(&self, app: &mut App) {
+        app.add_system_to_stage(
+            self.stage.clone(),
+            snap_to_partition::<P>
+                .label(TwoDSystem::SnapToPartition)
+                .after(TwoDSystem::FaceTarget)
+                .before(TwoDSystem::SyncDirectionRotation),
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! A uniform grid of entity positions, for fast range queries
+
+use crate::coordinate::Coordinate;
+use crate::position::Position;
+
+use bevy_ecs::prelude::*;
+use bevy_math::Vec2;
+use std::collections::HashMap;
+
+/// A uniform grid of the [`Position<C>`] of every entity, used to quickly find the entities near a point
+///
+/// Each entity is stored in the square cell of side length `cell_size` that contains it,
+/// so range queries only need to check the entities in nearby cells, rather than every entity in the world.
+/// Cells should be roughly as large as the radius of a typical query.
+///
+/// When the `spatial_hash` field of [`TwoDPlugin`](crate::plugin::TwoDPlugin) is set,
+/// this is inserted as a resource and rebuilt every frame by [`update_spatial_hash::<C>`].
+/// Entities that move after that system runs will not be seen at their new position until the next frame.
+///
+/// # Example
+/// ```rust
+/// use bevy::ecs::entity::Entity;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::spatial::SpatialHash;
+///
+/// let mut spatial_hash = SpatialHash::<F32>::new(10.0);
+/// spatial_hash.insert(Entity::from_raw(0), Position::new(1.0, 1.0));
+/// spatial_hash.insert(Entity::from_raw(1), Position::new(50.0, 0.0));
+///
+/// let nearby: Vec<Entity> = spatial_hash.query_radius(Position::default(), 5.0).collect();
+/// assert_eq!(nearby, vec![Entity::from_raw(0)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialHash<C: Coordinate> {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(Entity, Position<C>)>>,
+}
+
+impl<C: Coordinate> SpatialHash<C> {
+    /// Creates a new, empty [`SpatialHash`] whose cells have a side length of `cell_size`
+    ///
+    /// # Panics
+    /// Panics if `cell_size` is not a positive, finite number.
+    #[must_use]
+    pub fn new(cell_size: f32) -> SpatialHash<C> {
+        assert!(
+            cell_size.is_finite() && cell_size > 0.0,
+            "The cell size of a SpatialHash must be positive and finite, but was {cell_size}."
+        );
+
+        SpatialHash {
+            cell_size,
+            cells: HashMap::default(),
+        }
+    }
+
+    /// The side length of each cell
+    #[inline]
+    #[must_use]
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// The number of entities stored
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cells.values().map(Vec::len).sum()
+    }
+
+    /// Are there no entities stored?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cells.values().all(Vec::is_empty)
+    }
+
+    /// Removes all entities
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Stores `entity` at `position`
+    ///
+    /// Entities are not deduplicated: inserting the same entity twice will cause it to be returned twice by queries.
+    pub fn insert(&mut self, entity: Entity, position: Position<C>) {
+        let cell = self.cell(position.into());
+
+        self.cells.entry(cell).or_default().push((entity, position));
+    }
+
+    /// Returns every entity whose position is within `radius` of `center`
+    ///
+    /// Entities that lie exactly `radius` away are included.
+    /// Any radius is supported, including [`f32::INFINITY`], which returns every entity.
+    /// The entities are returned in no particular order.
+    pub fn query_radius(
+        &self,
+        center: Position<C>,
+        radius: f32,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        let center_vec2: Vec2 = center.into();
+        let min_cell = self.cell(center_vec2 - Vec2::splat(radius));
+        let max_cell = self.cell(center_vec2 + Vec2::splat(radius));
+        let radius_squared = radius * radius;
+
+        // For very large radii, visiting each covered cell would be slower than checking every occupied one
+        let covered_cells = (i64::from(max_cell.0) - i64::from(min_cell.0) + 1)
+            .saturating_mul(i64::from(max_cell.1) - i64::from(min_cell.1) + 1);
+        let check_every_cell = covered_cells > self.cells.len() as i64;
+
+        let covered = (!check_every_cell).then(|| {
+            (min_cell.0..=max_cell.0)
+                .flat_map(move |x| (min_cell.1..=max_cell.1).map(move |y| (x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+        });
+        let occupied = check_every_cell.then(|| self.cells.values());
+
+        covered
+            .into_iter()
+            .flatten()
+            .chain(occupied.into_iter().flatten())
+            .flatten()
+            .filter(move |(_, position)| center.distance_squared(*position) <= radius_squared)
+            .map(|&(entity, _)| entity)
+    }
+
+    /// The coordinates of the cell that contains `point`
+    fn cell(&self, point: Vec2) -> (i32, i32) {
+        (
+            (point.x / self.cell_size).floor() as i32,
+            (point.y / self.cell_size).floor() as i32,
+        )
+    }
+}
+
+/// Rebuilds the [`SpatialHash<C>`] resource from the [`Position<C>`] of every entity
+///
+/// Added by [`TwoDPlugin`](crate::plugin::TwoDPlugin) when its `spatial_hash` field is set.
+pub fn update_spatial_hash<C: Coordinate>(
+    mut spatial_hash: ResMut<SpatialHash<C>>,
+    query: Query<(Entity, &Position<C>)>,
+) {
+    spatial_hash.clear();
+
+    for (entity, &position) in query.iter() {
+        spatial_hash.insert(entity, position);
+    }
+}
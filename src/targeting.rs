@@ -0,0 +1,85 @@
+//! Tools for making entities turn to face other entities
+
+use crate::coordinate::Coordinate;
+use crate::orientation::{OrientationPositionInterop, Rotation};
+use crate::plugin::set_if_changed;
+use crate::position::Position;
+
+use bevy_ecs::prelude::*;
+
+/// Causes an entity to turn towards the [`Position`] of the `target` entity
+///
+/// Each time [`face_target`] runs (once per frame when used with [`TwoDPlugin`](crate::plugin::TwoDPlugin)),
+/// the [`Rotation`] of this entity is turned towards the target by at most `max_turn_rate`.
+/// This is the classic "turret tracks an enemy" behavior.
+///
+/// Both the tracking entity and its target must have a [`Position`].
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::prelude::*;
+/// use leafwing_2d::targeting::{face_target, FaceTarget};
+///
+/// let mut app = App::new();
+/// app.add_system(face_target::<F32>);
+///
+/// let enemy = app
+///     .world
+///     .spawn()
+///     .insert(Position::<F32>::new(10.0, 0.0))
+///     .id();
+///
+/// let turret = app
+///     .world
+///     .spawn()
+///     .insert(Position::<F32>::default())
+///     .insert(Rotation::NORTH)
+///     .insert(FaceTarget {
+///         target: enemy,
+///         max_turn_rate: Rotation::from_degrees(30.0),
+///     })
+///     .id();
+///
+/// app.update();
+///
+/// let rotation = *app.world.get::<Rotation>(turret).unwrap();
+/// rotation.assert_approx_eq(Rotation::from_degrees(30.0));
+/// ```
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FaceTarget {
+    /// The entity to turn towards
+    pub target: Entity,
+    /// The largest [`Rotation`] that can be applied each time [`face_target`] runs
+    pub max_turn_rate: Rotation,
+}
+
+/// Turns each entity with a [`FaceTarget`] component towards its target's [`Position`]
+///
+/// The [`Rotation`] is modified, and so the entity's [`Direction`](crate::orientation::Direction) and
+/// [`Transform`](bevy_transform::components::Transform) will follow once they are synchronized.
+///
+/// Nothing happens if the target entity no longer exists (or lacks a [`Position`]),
+/// or if the target is at the same position as the tracking entity.
+pub fn face_target<C: Coordinate>(
+    mut tracker_query: Query<(&mut Rotation, &Position<C>, &FaceTarget)>,
+    target_query: Query<&Position<C>>,
+) {
+    for (mut rotation, &position, face_target) in tracker_query.iter_mut() {
+        let target_position = match target_query.get(face_target.target) {
+            Ok(&target_position) => target_position,
+            Err(_) => continue,
+        };
+
+        // Coincident positions leave the rotation unchanged
+        let mut new_rotation = *rotation;
+        new_rotation.rotate_towards_position(
+            position,
+            target_position,
+            Some(face_target.max_turn_rate),
+        );
+
+        // Avoid triggering change detection when we are already facing the target
+        set_if_changed(&mut rotation, new_rotation);
+    }
+}
@@ -0,0 +1,207 @@
+//! A 2D analogue of [`Transform`], for use outside of the ECS
+
+use crate::coordinate::Coordinate;
+use crate::orientation::{Direction, Rotation};
+use crate::position::Position;
+
+use bevy_math::{Quat, Vec2, Vec3};
+use bevy_transform::components::Transform;
+
+/// The complete 2D state of an object: its [`Position`] and [`Rotation`]
+///
+/// This is a plain value type, and performs the same conversions to and from [`Transform`]
+/// as [`sync_transform_with_2d`](crate::plugin::sync_transform_with_2d).
+/// This makes it useful for tooling and tests that want to work with 2D transforms without spawning entities.
+///
+/// When converting from a [`Transform`], the z-value and scale are discarded.
+/// Use [`TwoDTransform::apply_to`] to preserve them when converting back.
+/// These conversions use the [`Plane::Xy`] plane: use [`TwoDTransform::from_plane`]
+/// and [`TwoDTransform::apply_to_plane`] for other planes.
+///
+/// # Example
+/// ```rust
+/// use bevy::prelude::*;
+/// use leafwing_2d::continuous::F32;
+/// use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+/// use leafwing_2d::position::Position;
+/// use leafwing_2d::transform::TwoDTransform;
+///
+/// let two_d = TwoDTransform::<F32>::new(Position::new(3.0, 4.0), Rotation::SOUTH);
+/// two_d.direction().assert_approx_eq(Direction::SOUTH);
+///
+/// let transform: Transform = two_d.into();
+/// assert_eq!(transform.translation, Vec3::new(3.0, 4.0, 0.0));
+///
+/// let round_trip = TwoDTransform::<F32>::from(&transform);
+/// assert_eq!(round_trip.position, two_d.position);
+/// round_trip.rotation.assert_approx_eq(two_d.rotation);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TwoDTransform<C: Coordinate> {
+    /// The 2-dimensional [`Position`] of the object
+    pub position: Position<C>,
+    /// Which way the object is facing, stored as an angle from due north
+    pub rotation: Rotation,
+}
+
+impl<C: Coordinate> TwoDTransform<C> {
+    /// Creates a new [`TwoDTransform`] from its `position` and `rotation`
+    #[inline]
+    #[must_use]
+    pub fn new(position: Position<C>, rotation: Rotation) -> Self {
+        Self { position, rotation }
+    }
+
+    /// Which way the object is facing, stored as a unit vector
+    ///
+    /// This is derived from [`TwoDTransform::rotation`].
+    #[inline]
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        self.rotation.into()
+    }
+
+    /// Overwrites the x, y and rotation of `transform`, leaving its z-value and scale untouched
+    #[inline]
+    pub fn apply_to(&self, transform: &mut Transform) {
+        self.apply_to_plane(transform, Plane::Xy);
+    }
+
+    /// Overwrites the translation and rotation of `transform` within `plane`,
+    /// leaving the axis perpendicular to `plane` and the scale untouched
+    ///
+    /// # Example
+    /// ```rust
+    /// use bevy::prelude::*;
+    /// use leafwing_2d::continuous::F32;
+    /// use leafwing_2d::orientation::Rotation;
+    /// use leafwing_2d::position::Position;
+    /// use leafwing_2d::transform::{Plane, TwoDTransform};
+    ///
+    /// let two_d = TwoDTransform::<F32>::new(Position::new(3.0, 4.0), Rotation::NORTH);
+    /// let mut transform = Transform::from_xyz(0.0, 1.0, 0.0);
+    /// two_d.apply_to_plane(&mut transform, Plane::Xz);
+    ///
+    /// assert_eq!(transform.translation, Vec3::new(3.0, 1.0, -4.0));
+    /// ```
+    #[inline]
+    pub fn apply_to_plane(&self, transform: &mut Transform, plane: Plane) {
+        let position = Vec2::new(self.position.x.into(), self.position.y.into());
+        transform.translation = plane.with_translation(transform.translation, position);
+        transform.rotation = plane.rotation_from_xy(self.rotation.into());
+    }
+
+    /// Reads the position and rotation of `transform` within `plane`
+    ///
+    /// The axis perpendicular to `plane` and the scale are discarded.
+    #[inline]
+    #[must_use]
+    pub fn from_plane(transform: &Transform, plane: Plane) -> Self {
+        Self {
+            position: Position::from(plane.project_translation(transform.translation)),
+            rotation: Rotation::from(plane.rotation_to_xy(transform.rotation)),
+        }
+    }
+}
+
+impl<C: Coordinate> From<&Transform> for TwoDTransform<C> {
+    fn from(transform: &Transform) -> Self {
+        Self::from_plane(transform, Plane::Xy)
+    }
+}
+
+impl<C: Coordinate> From<Transform> for TwoDTransform<C> {
+    fn from(transform: Transform) -> Self {
+        Self::from(&transform)
+    }
+}
+
+impl<C: Coordinate> From<TwoDTransform<C>> for Transform {
+    fn from(two_d: TwoDTransform<C>) -> Transform {
+        let mut transform = Transform::identity();
+        two_d.apply_to(&mut transform);
+        transform
+    }
+}
+
+/// The plane of the 3D world that the 2D components of an entity are mapped onto
+///
+/// Stored as a resource by [`TwoDPlugin`](crate::plugin::TwoDPlugin), and read by [`sync_transform_with_2d`](crate::plugin::sync_transform_with_2d).
+/// In both planes, [`Rotation`] turns clockwise when viewed from the side that the plane faces.
+///
+/// # Example
+/// ```rust
+/// use bevy::math::{Quat, Vec2, Vec3};
+/// use leafwing_2d::orientation::Rotation;
+/// use leafwing_2d::transform::Plane;
+///
+/// let translation = Vec3::new(1.0, 2.0, 3.0);
+/// assert_eq!(Plane::Xy.project_translation(translation), Vec2::new(1.0, 2.0));
+/// assert_eq!(Plane::Xz.project_translation(translation), Vec2::new(1.0, -3.0));
+///
+/// // North is Bevy's forward direction in the XZ plane
+/// let quat = Plane::Xz.rotation_from_xy(Rotation::EAST.into());
+/// assert!((quat * -Vec3::Z).abs_diff_eq(Vec3::X, 1e-6));
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum Plane {
+    /// The XY plane, viewed from +z
+    ///
+    /// The x and y of [`Position`] map to the x and y of the translation, and [`Rotation`] turns around the z axis.
+    /// This is the default, and is appropriate for 2D games.
+    #[default]
+    Xy,
+    /// The XZ plane, viewed from +y
+    ///
+    /// The x of [`Position`] maps to the x of the translation, and its y maps to -z,
+    /// so that north is Bevy's forward direction. [`Rotation`] turns around the y axis.
+    /// This is appropriate for 2.5D games that use a y-up 3D world.
+    Xz,
+}
+
+impl Plane {
+    /// The 2D coordinates of `translation` within this plane
+    #[inline]
+    #[must_use]
+    pub fn project_translation(self, translation: Vec3) -> Vec2 {
+        match self {
+            Plane::Xy => Vec2::new(translation.x, translation.y),
+            Plane::Xz => Vec2::new(translation.x, -translation.z),
+        }
+    }
+
+    /// Returns `translation`, with its coordinates within this plane replaced by `position`
+    ///
+    /// The axis perpendicular to this plane is left unchanged.
+    #[inline]
+    #[must_use]
+    pub fn with_translation(self, translation: Vec3, position: Vec2) -> Vec3 {
+        match self {
+            Plane::Xy => Vec3::new(position.x, position.y, translation.z),
+            Plane::Xz => Vec3::new(position.x, translation.y, -position.y),
+        }
+    }
+
+    /// Converts a rotation of the XY plane, such as one created from a [`Rotation`], into the equivalent rotation in this plane
+    #[inline]
+    #[must_use]
+    pub fn rotation_from_xy(self, quat: Quat) -> Quat {
+        match self {
+            Plane::Xy => quat,
+            // Rotates the axis of rotation from +z to +y, carrying +y to -z
+            Plane::Xz => Quat::from_xyzw(quat.x, quat.z, -quat.y, quat.w),
+        }
+    }
+
+    /// Converts a rotation in this plane back into the equivalent rotation of the XY plane
+    ///
+    /// This is the inverse of [`Plane::rotation_from_xy`].
+    #[inline]
+    #[must_use]
+    pub fn rotation_to_xy(self, quat: Quat) -> Quat {
+        match self {
+            Plane::Xy => quat,
+            Plane::Xz => Quat::from_xyzw(quat.x, -quat.z, quat.y, quat.w),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "approx")]
+
+use approx::{assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq};
+use bevy::math::Vec2;
+use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
+use leafwing_2d::orientation::{Direction, Rotation};
+use leafwing_2d::position::Position;
+
+#[test]
+fn rotation_abs_diff_eq() {
+    assert_abs_diff_eq!(Rotation::new(901), Rotation::EAST);
+    assert_abs_diff_ne!(Rotation::new(910), Rotation::EAST);
+    assert_abs_diff_eq!(
+        Rotation::new(910),
+        Rotation::EAST,
+        epsilon = Rotation::from_degrees(1.0)
+    );
+
+    // The shortest distance is used, even across midnight
+    assert_abs_diff_eq!(Rotation::new(3599), Rotation::NORTH);
+    assert_relative_eq!(Rotation::new(3599), Rotation::NORTH);
+}
+
+#[test]
+fn direction_abs_diff_eq() {
+    assert_abs_diff_eq!(Direction::new(Vec2::new(1.0, 1.0001)), Direction::NORTHEAST);
+    assert_abs_diff_ne!(Direction::EAST, Direction::NORTHEAST);
+    assert_abs_diff_eq!(
+        Direction::EAST,
+        Direction::NORTHEAST,
+        epsilon = Rotation::from_degrees(45.0)
+    );
+    assert_relative_eq!(Direction::from(Rotation::SOUTH), Direction::SOUTH);
+
+    // Neutral directions are only equal to each other
+    assert_abs_diff_eq!(Direction::NEUTRAL, Direction::NEUTRAL);
+    assert_abs_diff_ne!(Direction::NEUTRAL, Direction::NORTH);
+    assert_abs_diff_ne!(Direction::NORTH, Direction::NEUTRAL);
+}
+
+#[test]
+fn position_abs_diff_eq() {
+    assert_abs_diff_eq!(
+        Position::<F32>::new(0.1 + 0.2, 1.0),
+        Position::new(0.3, 1.0)
+    );
+    assert_abs_diff_ne!(Position::<F32>::new(0.5, 1.0), Position::new(0.3, 1.0));
+    assert_abs_diff_eq!(
+        Position::<F32>::new(0.5, 1.0),
+        Position::new(0.3, 1.1),
+        epsilon = 0.25
+    );
+    assert_relative_eq!(
+        Position::<F32>::new(1000.0, -1000.0),
+        Position::new(1000.01, -1000.01),
+        max_relative = 1e-4
+    );
+
+    assert_abs_diff_eq!(Position::<OrthogonalGrid>::new(1, 2), Position::new(1, 2));
+    assert_abs_diff_ne!(Position::<OrthogonalGrid>::new(1, 2), Position::new(1, 3));
+}
@@ -0,0 +1,117 @@
+use leafwing_2d::compass::{Compass16, Compass8};
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+
+#[test]
+fn compass8_boundaries() {
+    assert_eq!(Rotation::from_degrees(22.4).compass8(), Compass8::N);
+    assert_eq!(Rotation::from_degrees(22.6).compass8(), Compass8::NE);
+    assert_eq!(Rotation::from_degrees(337.6).compass8(), Compass8::N);
+    assert_eq!(Rotation::from_degrees(337.4).compass8(), Compass8::NW);
+
+    // Ties go clockwise
+    assert_eq!(Rotation::new(225).compass8(), Compass8::NE);
+}
+
+#[test]
+fn compass16_boundaries() {
+    assert_eq!(Rotation::from_degrees(11.2).compass16(), Compass16::N);
+    assert_eq!(Rotation::from_degrees(11.3).compass16(), Compass16::NNE);
+    assert_eq!(Rotation::from_degrees(22.4).compass16(), Compass16::NNE);
+    assert_eq!(Rotation::from_degrees(22.6).compass16(), Compass16::NNE);
+    assert_eq!(Rotation::from_degrees(33.7).compass16(), Compass16::NNE);
+    assert_eq!(Rotation::from_degrees(33.8).compass16(), Compass16::NE);
+    assert_eq!(Rotation::from_degrees(348.8).compass16(), Compass16::N);
+    assert_eq!(Rotation::from_degrees(348.7).compass16(), Compass16::NNW);
+}
+
+#[test]
+fn compass_points_round_trip() {
+    for point in Compass8::POINTS {
+        assert_eq!(Rotation::from(point).compass8(), point);
+    }
+
+    for point in Compass16::POINTS {
+        assert_eq!(Rotation::from(point).compass16(), point);
+    }
+
+    assert_eq!(Rotation::from(Compass8::SW), Rotation::SOUTHWEST);
+    assert_eq!(
+        Rotation::from(Compass16::WNW),
+        Rotation::from_degrees(292.5)
+    );
+}
+
+#[test]
+fn compass_labels() {
+    assert_eq!(Compass8::NE.to_string(), "NE");
+    assert_eq!(Compass16::SSW.to_string(), "SSW");
+    assert_eq!(Rotation::EAST.compass16().to_string(), "E");
+}
+
+#[test]
+fn parse_compass_abbreviations() {
+    assert_eq!("N".parse::<Rotation>().unwrap(), Rotation::NORTH);
+    assert_eq!("ne".parse::<Rotation>().unwrap(), Rotation::NORTHEAST);
+    assert_eq!(" SW ".parse::<Rotation>().unwrap(), Rotation::SOUTHWEST);
+    assert_eq!(
+        "NNE".parse::<Rotation>().unwrap(),
+        Rotation::from(Compass16::NNE)
+    );
+}
+
+#[test]
+fn parse_compass_names() {
+    assert_eq!("north".parse::<Rotation>().unwrap(), Rotation::NORTH);
+    assert_eq!("East".parse::<Rotation>().unwrap(), Rotation::EAST);
+    assert_eq!(
+        "south-west".parse::<Rotation>().unwrap(),
+        Rotation::SOUTHWEST
+    );
+    assert_eq!(
+        "NorthWest".parse::<Rotation>().unwrap(),
+        Rotation::NORTHWEST
+    );
+}
+
+#[test]
+fn parse_angles() {
+    assert_eq!("90deg".parse::<Rotation>().unwrap(), Rotation::EAST);
+    assert_eq!("-90 deg".parse::<Rotation>().unwrap(), Rotation::WEST);
+    assert_eq!("450deg".parse::<Rotation>().unwrap(), Rotation::EAST);
+    assert_eq!("0rad".parse::<Rotation>().unwrap(), Rotation::NORTH);
+    "3.14159rad"
+        .parse::<Rotation>()
+        .unwrap()
+        .assert_approx_eq(Rotation::SOUTH);
+
+    // Display output can be parsed back
+    let rotation = Rotation::from_degrees(123.4);
+    assert_eq!(rotation.to_string().parse::<Rotation>().unwrap(), rotation);
+}
+
+#[test]
+fn parse_direction() {
+    assert_eq!("S".parse::<Direction>().unwrap(), Direction::SOUTH);
+    "45deg"
+        .parse::<Direction>()
+        .unwrap()
+        .assert_approx_eq(Direction::NORTHEAST);
+}
+
+#[test]
+fn parse_garbage() {
+    for input in [
+        "",
+        "sideways",
+        "NNNE",
+        "deg",
+        "90",
+        "ninetydeg",
+        "infdeg",
+        "NaNrad",
+    ] {
+        let error = input.parse::<Rotation>().unwrap_err();
+        assert_eq!(error.input, input);
+    }
+    assert!("up".parse::<Direction>().is_err());
+}
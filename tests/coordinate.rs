@@ -0,0 +1,101 @@
+use bevy::transform::components::Transform;
+use leafwing_2d::continuous::{Saturating, F32, F64};
+use leafwing_2d::coordinate::Coordinate;
+use leafwing_2d::discrete::{FlatHex, OrthogonalGrid};
+use leafwing_2d::errors::CoordinateConversionError;
+use leafwing_2d::position::Position;
+
+#[test]
+fn try_from_f32_in_range() {
+    assert_eq!(F32::try_from_f32(-2.5), Ok(F32(-2.5)));
+    assert_eq!(
+        Saturating::<-10, 10>::try_from_f32(10.0),
+        Ok(Saturating::from(10.0))
+    );
+
+    // Discrete coordinates are still rounded
+    assert_eq!(OrthogonalGrid::try_from_f32(1.6), Ok(OrthogonalGrid(2)));
+    assert_eq!(FlatHex::try_from_f32(-0.4), Ok(FlatHex(0)));
+}
+
+#[test]
+fn try_from_f32_overflow() {
+    // `From<f32>` silently saturates these values
+    assert_eq!(
+        OrthogonalGrid::try_from_f32(1e20),
+        Err(CoordinateConversionError::OutOfRange { value: 1e20 })
+    );
+    assert_eq!(
+        FlatHex::try_from_f32(-1e20),
+        Err(CoordinateConversionError::OutOfRange { value: -1e20 })
+    );
+    assert_eq!(
+        Saturating::<-10, 10>::try_from_f32(10.5),
+        Err(CoordinateConversionError::OutOfRange { value: 10.5 })
+    );
+}
+
+#[test]
+fn try_from_f32_non_finite() {
+    assert!(matches!(
+        OrthogonalGrid::try_from_f32(f32::NAN),
+        Err(CoordinateConversionError::NonFinite { value }) if value.is_nan()
+    ));
+    assert!(matches!(
+        F32::try_from_f32(f32::NAN),
+        Err(CoordinateConversionError::NonFinite { value }) if value.is_nan()
+    ));
+    assert_eq!(
+        F32::try_from_f32(f32::INFINITY),
+        Err(CoordinateConversionError::NonFinite {
+            value: f32::INFINITY
+        })
+    );
+}
+
+#[test]
+fn f64_positions_survive_arithmetic() {
+    let start = Position::<F64>::new(1.5e9, -2.5e9);
+    let step = Position::<F64>::new(0.001, 0.125);
+
+    let mut position = start;
+    for _ in 0..1000 {
+        position = position + step;
+    }
+    assert!((position.x.0 - (1.5e9 + 1.0)).abs() < 1e-4);
+    assert!((position.y.0 - (-2.5e9 + 125.0)).abs() < 1e-4);
+
+    let returned = position - step * F64(1000.0);
+    assert!((returned.x.0 - start.x.0).abs() < 1e-4);
+    assert!((returned.y.0 - start.y.0).abs() < 1e-4);
+
+    assert_eq!((start * F64(2.0)) / F64(2.0), start);
+}
+
+#[test]
+fn f64_is_more_precise_than_f32_at_large_magnitudes() {
+    let step = 0.5;
+
+    let mut f32_position = Position::<F32>::new(1e8, 0.0);
+    let mut f64_position = Position::<F64>::new(1e8, 0.0);
+    for _ in 0..10 {
+        f32_position = f32_position + Position::new(step, 0.0);
+        f64_position = f64_position + Position::new(step as f64, 0.0);
+    }
+
+    // Each half-unit step is smaller than the spacing between adjacent `f32` values near 1e8
+    assert_eq!(f32_position.x, F32(1e8));
+    assert_eq!(f64_position.x, F64(1e8 + 5.0));
+}
+
+#[test]
+fn f64_converts_to_nearest_f32() {
+    assert_eq!(f32::from(F64(0.1)), 0.1_f32);
+    assert_eq!(F64::from(2.5_f32), F64(2.5));
+    assert_eq!(f32::from(F64(1e300)), f32::INFINITY);
+    assert_eq!(F64::try_from_f32(-3.0), Ok(F64(-3.0)));
+
+    let transform: Transform = Position::<F64>::new(1e9 + 0.25, 2.0).into();
+    assert_eq!(transform.translation.x, 1e9);
+    assert_eq!(transform.translation.y, 2.0);
+}
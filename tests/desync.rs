@@ -0,0 +1,69 @@
+#![cfg(feature = "desync_warnings")]
+
+use bevy::prelude::*;
+use leafwing_2d::plugin::TransformDesyncs;
+use leafwing_2d::prelude::*;
+
+fn nudge_transform(mut query: Query<&mut Transform>) {
+    for mut transform in query.iter_mut() {
+        transform.translation.x += 1.0;
+    }
+}
+
+fn test_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    });
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::default())
+        .id();
+
+    (app, entity)
+}
+
+#[test]
+fn writes_after_sync_are_reported() {
+    let (mut app, entity) = test_app();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        nudge_transform.after(TwoDSystem::SyncTransform),
+    );
+
+    app.update();
+    app.update();
+
+    let desyncs = app.world.resource::<TransformDesyncs>();
+    assert_eq!(desyncs.entities, vec![entity]);
+}
+
+#[test]
+fn writes_before_sync_are_not_reported() {
+    let (mut app, _entity) = test_app();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        nudge_transform.before(TwoDSystem::SyncTransform),
+    );
+
+    app.update();
+    app.update();
+
+    let desyncs = app.world.resource::<TransformDesyncs>();
+    assert!(desyncs.entities.is_empty());
+}
+
+#[test]
+fn entities_at_rest_are_not_reported() {
+    let (mut app, _entity) = test_app();
+
+    app.update();
+    app.update();
+
+    let desyncs = app.world.resource::<TransformDesyncs>();
+    assert!(desyncs.entities.is_empty());
+}
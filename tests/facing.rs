@@ -0,0 +1,83 @@
+use bevy::ecs::event::Events;
+use bevy::prelude::*;
+use leafwing_2d::prelude::*;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    })
+    .add_plugin(FacingEventsPlugin::<CardinalQuadrant>::default());
+
+    app
+}
+
+fn set_rotation(app: &mut App, entity: Entity, rotation: Rotation) {
+    *app.world.get_mut::<Rotation>(entity).unwrap() = rotation;
+    app.update();
+}
+
+fn drain_events(app: &mut App) -> Vec<FacingChanged<CardinalQuadrant>> {
+    app.world
+        .resource_mut::<Events<FacingChanged<CardinalQuadrant>>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn crossing_boundary_sends_one_event() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Rotation::from_degrees(30.0))
+        .insert(TrackFacing::<CardinalQuadrant>::default())
+        .id();
+
+    // The initial facing is recorded without an event
+    app.update();
+    assert_eq!(drain_events(&mut app), vec![]);
+    assert_eq!(
+        app.world
+            .get::<TrackFacing<CardinalQuadrant>>(entity)
+            .unwrap()
+            .current(),
+        Some(CardinalQuadrant::North)
+    );
+
+    // Turning within the same quadrant does nothing
+    set_rotation(&mut app, entity, Rotation::from_degrees(40.0));
+    assert_eq!(drain_events(&mut app), vec![]);
+
+    // Crossing the boundary between north and east at 45 degrees
+    set_rotation(&mut app, entity, Rotation::from_degrees(50.0));
+    assert_eq!(
+        drain_events(&mut app),
+        vec![FacingChanged {
+            entity,
+            from: CardinalQuadrant::North,
+            to: CardinalQuadrant::East,
+        }]
+    );
+
+    // Staying in the new quadrant does not send another event
+    set_rotation(&mut app, entity, Rotation::from_degrees(60.0));
+    app.update();
+    assert_eq!(drain_events(&mut app), vec![]);
+}
+
+#[test]
+fn untracked_entities_send_no_events() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .id();
+
+    app.update();
+    set_rotation(&mut app, entity, Rotation::SOUTH);
+    assert_eq!(drain_events(&mut app), vec![]);
+}
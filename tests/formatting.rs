@@ -0,0 +1,93 @@
+use leafwing_2d::orientation::{AngleUnit, Direction, Rotation};
+
+#[test]
+fn rotation_display() {
+    assert_eq!(Rotation::NORTH.to_string(), "0.0°");
+    assert_eq!(Rotation::EAST.to_string(), "90.0°");
+    assert_eq!(Rotation::new(3599).to_string(), "359.9°");
+    assert_eq!(Rotation::from_degrees(-90.0).to_string(), "270.0°");
+}
+
+#[test]
+fn direction_display() {
+    assert_eq!(Direction::NORTH.to_string(), "N [0, 1]");
+    assert_eq!(Direction::WEST.to_string(), "W [-1, 0]");
+    assert!(Direction::NORTHEAST.to_string().starts_with("NE ["));
+    assert!(Direction::SOUTHWEST.to_string().starts_with("SW ["));
+    assert_eq!(Direction::NEUTRAL.to_string(), "neutral");
+}
+
+#[test]
+fn direction_display_uses_nearest_compass_point() {
+    let almost_east: Direction = Rotation::from_degrees(80.0).into();
+    assert!(almost_east.to_string().starts_with("E ["));
+
+    let almost_north: Direction = Rotation::from_degrees(350.0).into();
+    assert!(almost_north.to_string().starts_with("N ["));
+}
+
+#[test]
+fn rotation_display_as_degrees() {
+    assert_eq!(
+        Rotation::EAST.display_as(AngleUnit::Degrees).to_string(),
+        "90.0°"
+    );
+    assert_eq!(
+        Rotation::new(3599)
+            .display_as(AngleUnit::Degrees)
+            .to_string(),
+        "359.9°"
+    );
+    assert_eq!(
+        format!("{:.3}", Rotation::WEST.display_as(AngleUnit::Degrees)),
+        "270.000°"
+    );
+
+    // Matches the `Display` impl of `Rotation`
+    let rotation = Rotation::new(1234);
+    assert_eq!(
+        rotation.display_as(AngleUnit::Degrees).to_string(),
+        rotation.to_string()
+    );
+}
+
+#[test]
+fn rotation_display_as_radians() {
+    assert_eq!(
+        Rotation::NORTH.display_as(AngleUnit::Radians).to_string(),
+        "0.000 rad"
+    );
+    assert_eq!(
+        Rotation::EAST.display_as(AngleUnit::Radians).to_string(),
+        "1.571 rad"
+    );
+    assert_eq!(
+        Rotation::SOUTH.display_as(AngleUnit::Radians).to_string(),
+        "3.142 rad"
+    );
+    assert_eq!(
+        format!("{:.1}", Rotation::WEST.display_as(AngleUnit::Radians)),
+        "4.7 rad"
+    );
+}
+
+#[test]
+fn rotation_display_as_deci_degrees() {
+    assert_eq!(
+        Rotation::NORTH
+            .display_as(AngleUnit::DeciDegrees)
+            .to_string(),
+        "0 deci-degrees"
+    );
+    assert_eq!(
+        Rotation::new(3599)
+            .display_as(AngleUnit::DeciDegrees)
+            .to_string(),
+        "3599 deci-degrees"
+    );
+    // Precision is ignored, as deci-degrees are always whole numbers
+    assert_eq!(
+        format!("{:.2}", Rotation::EAST.display_as(AngleUnit::DeciDegrees)),
+        "900 deci-degrees"
+    );
+}
@@ -0,0 +1,1337 @@
+use bevy::math::{const_vec2, Vec2};
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+use std::collections::HashSet;
+
+const CARDINALS: [Rotation; 4] = [
+    Rotation::NORTH,
+    Rotation::EAST,
+    Rotation::SOUTH,
+    Rotation::WEST,
+];
+
+const DIAGONALS: [Rotation; 4] = [
+    Rotation::NORTHEAST,
+    Rotation::SOUTHEAST,
+    Rotation::SOUTHWEST,
+    Rotation::NORTHWEST,
+];
+
+#[test]
+fn cardinal_rotations() {
+    for rotation in CARDINALS {
+        assert!(rotation.is_cardinal(), "{rotation:?} should be cardinal");
+        assert!(
+            !rotation.is_diagonal(),
+            "{rotation:?} should not be diagonal"
+        );
+    }
+}
+
+#[test]
+fn diagonal_rotations() {
+    for rotation in DIAGONALS {
+        assert!(rotation.is_diagonal(), "{rotation:?} should be diagonal");
+        assert!(
+            !rotation.is_cardinal(),
+            "{rotation:?} should not be cardinal"
+        );
+    }
+}
+
+#[test]
+fn off_axis_rotations() {
+    for degrees in [100.0, 0.1, 44.9, 45.1, 359.9] {
+        let rotation = Rotation::from_degrees(degrees);
+        assert!(
+            !rotation.is_cardinal(),
+            "{rotation:?} should not be cardinal"
+        );
+        assert!(
+            !rotation.is_diagonal(),
+            "{rotation:?} should not be diagonal"
+        );
+    }
+
+    // Full turns are normalized back onto the cardinal directions
+    assert!(Rotation::from_degrees(360.0).is_cardinal());
+    assert!(Rotation::from_degrees(-90.0).is_cardinal());
+}
+
+#[test]
+fn direction_scaled() {
+    assert_eq!(Direction::EAST.scaled(2.0), Vec2::new(2.0, 0.0));
+    assert_eq!(Direction::NORTH.scaled(-1.0), Vec2::new(0.0, -1.0));
+    assert_eq!(Direction::NORTHWEST.scaled(5.0), Direction::NORTHWEST * 5.0);
+}
+
+#[test]
+fn direction_halfway() {
+    Direction::NORTH
+        .halfway(Direction::EAST)
+        .assert_approx_eq(Direction::NORTHEAST);
+    Direction::EAST
+        .halfway(Direction::NORTH)
+        .assert_approx_eq(Direction::NORTHEAST);
+    Direction::SOUTH
+        .halfway(Direction::WEST)
+        .assert_approx_eq(Direction::SOUTHWEST);
+
+    // The bisector of a direction with itself is unchanged
+    Direction::SOUTHEAST
+        .halfway(Direction::SOUTHEAST)
+        .assert_approx_eq(Direction::SOUTHEAST);
+
+    // Opposite directions have no unique bisector
+    assert_eq!(Direction::EAST.halfway(Direction::WEST), Direction::NEUTRAL);
+}
+
+#[test]
+fn rotate_towards_reports_arrival() {
+    let step = Some(Rotation::from_degrees(45.0));
+    let mut rotation = Rotation::NORTH;
+
+    // Each step falls short of the target
+    assert!(!rotation.rotate_towards(Rotation::SOUTH, step));
+    assert_eq!(rotation, Rotation::NORTHEAST);
+    assert!(!rotation.rotate_towards(Rotation::SOUTH, step));
+    assert!(!rotation.rotate_towards(Rotation::SOUTH, step));
+    assert_eq!(rotation, Rotation::SOUTHEAST);
+
+    // Landing exactly on the target
+    assert!(rotation.rotate_towards(Rotation::SOUTH, step));
+    assert_eq!(rotation, Rotation::SOUTH);
+
+    // Already at the target
+    assert!(rotation.rotate_towards(Rotation::SOUTH, step));
+    assert_eq!(rotation, Rotation::SOUTH);
+
+    // A step that would pass the target stops on it
+    assert!(rotation.rotate_towards(Rotation::from_degrees(200.0), step));
+    assert_eq!(rotation, Rotation::from_degrees(200.0));
+
+    // Snapping always reaches the target
+    assert!(rotation.rotate_towards(Rotation::WEST, None));
+    assert_eq!(rotation, Rotation::WEST);
+}
+
+#[test]
+fn direction_from_vec2_with_magnitude() {
+    let (direction, magnitude) = Direction::from_vec2_with_magnitude(Vec2::new(3.0, 4.0));
+    direction.assert_approx_eq(Direction::new(Vec2::new(0.6, 0.8)));
+    assert!((direction.unit_vector() - Vec2::new(0.6, 0.8)).length() < 1e-6);
+    assert!((magnitude - 5.0).abs() < 1e-6);
+
+    let (direction, magnitude) = Direction::from_vec2_with_magnitude(Vec2::new(0.0, 3.0));
+    assert_eq!(direction, Direction::NORTH);
+    assert_eq!(magnitude, 3.0);
+
+    let (direction, magnitude) = Direction::from_vec2_with_magnitude(Vec2::new(-0.5, 0.0));
+    assert_eq!(direction, Direction::WEST);
+    assert_eq!(magnitude, 0.5);
+
+    // Near-zero vectors have no direction
+    assert_eq!(
+        Direction::from_vec2_with_magnitude(Vec2::ZERO),
+        (Direction::NEUTRAL, 0.0)
+    );
+    assert_eq!(
+        Direction::from_vec2_with_magnitude(Vec2::new(f32::EPSILON / 2.0, 0.0)),
+        (Direction::NEUTRAL, 0.0)
+    );
+}
+
+#[test]
+fn display_degrees_boundaries() {
+    assert_eq!(Rotation::from_display_degrees(0.0), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(-0.0), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(360.0), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(720.0), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(359.9), Rotation::new(3599));
+
+    // Values that round up to a full circle wrap back to 0
+    assert_eq!(Rotation::from_display_degrees(359.96), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(-0.01), Rotation::NORTH);
+    assert_eq!(Rotation::from_display_degrees(-1e-8), Rotation::NORTH);
+
+    assert_eq!(Rotation::from_display_degrees(-90.0), Rotation::WEST);
+    assert_eq!(Rotation::from_display_degrees(-0.1), Rotation::new(3599));
+}
+
+#[test]
+fn display_degrees_round_trip() {
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let rotation = Rotation::new(deci_degrees);
+        let display_degrees = rotation.into_display_degrees();
+
+        assert!((0.0..360.0).contains(&display_degrees));
+        assert_eq!(Rotation::from_display_degrees(display_degrees), rotation);
+    }
+}
+
+#[test]
+fn turns_round_trip() {
+    for (turns, rotation) in [
+        (0.0, Rotation::NORTH),
+        (0.25, Rotation::EAST),
+        (0.5, Rotation::SOUTH),
+        (0.75, Rotation::WEST),
+    ] {
+        assert_eq!(Rotation::from_turns(turns), rotation);
+        assert_eq!(rotation.as_turns(), turns);
+    }
+}
+
+#[test]
+fn turns_are_normalized() {
+    assert_eq!(Rotation::from_turns(1.0), Rotation::NORTH);
+    assert_eq!(Rotation::from_turns(1.25), Rotation::EAST);
+    assert_eq!(Rotation::from_turns(-0.25), Rotation::WEST);
+    assert_eq!(Rotation::from_turns(-1e-8), Rotation::NORTH);
+    assert_eq!(Rotation::from_turns(f32::NAN), Rotation::NORTH);
+    assert_eq!(Rotation::from_turns(f32::INFINITY), Rotation::NORTH);
+
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        assert!((0.0..1.0).contains(&Rotation::new(deci_degrees).as_turns()));
+    }
+}
+
+#[test]
+fn direction_rotation_to() {
+    let rotation = Direction::NORTH.rotation_to(Direction::EAST).unwrap();
+    rotation.assert_approx_eq(Rotation::EAST);
+
+    // Applying the rotation to the original direction recovers the target
+    let recovered: Direction = (Rotation::from(Direction::NORTH) + rotation).into();
+    recovered.assert_approx_eq(Direction::EAST);
+
+    // Rotations are always measured clockwise
+    Direction::NORTH
+        .rotation_to(Direction::WEST)
+        .unwrap()
+        .assert_approx_eq(Rotation::from_degrees(270.0));
+    Direction::SOUTHWEST
+        .rotation_to(Direction::SOUTHEAST)
+        .unwrap()
+        .assert_approx_eq(Rotation::from_degrees(270.0));
+
+    // No rotation is required to reach the same direction
+    Direction::SOUTH
+        .rotation_to(Direction::SOUTH)
+        .unwrap()
+        .assert_approx_eq(Rotation::NORTH);
+}
+
+#[test]
+fn neutral_direction_rotation_to() {
+    assert!(Direction::NEUTRAL.rotation_to(Direction::EAST).is_err());
+    assert!(Direction::EAST.rotation_to(Direction::NEUTRAL).is_err());
+    assert!(Direction::NEUTRAL.rotation_to(Direction::NEUTRAL).is_err());
+}
+
+#[test]
+fn fan_is_symmetric() {
+    let spacing = Rotation::from_degrees(10.0);
+    let center = Rotation::EAST;
+
+    let spread: Vec<Rotation> = Rotation::fan(center, 3, spacing).collect();
+    assert_eq!(
+        spread,
+        vec![
+            Rotation::from_degrees(80.0),
+            Rotation::EAST,
+            Rotation::from_degrees(100.0)
+        ]
+    );
+
+    // Each rotation is mirrored by another on the opposite side of the center
+    for (left, right) in spread.iter().zip(spread.iter().rev()) {
+        assert_eq!(center - *left, *right - center);
+    }
+}
+
+#[test]
+fn fan_edge_cases() {
+    let spacing = Rotation::from_degrees(10.0);
+
+    assert_eq!(Rotation::fan(Rotation::SOUTH, 0, spacing).count(), 0);
+
+    let single: Vec<Rotation> = Rotation::fan(Rotation::SOUTH, 1, spacing).collect();
+    assert_eq!(single, vec![Rotation::SOUTH]);
+
+    // Even counts straddle the center
+    let pair: Vec<Rotation> = Rotation::fan(Rotation::SOUTH, 2, spacing).collect();
+    assert_eq!(
+        pair,
+        vec![Rotation::from_degrees(175.0), Rotation::from_degrees(185.0)]
+    );
+
+    // Spreads wrap around midnight
+    let wrapped: Vec<Rotation> = Rotation::fan(Rotation::NORTH, 4, spacing).collect();
+    assert_eq!(
+        wrapped,
+        vec![
+            Rotation::from_degrees(345.0),
+            Rotation::from_degrees(355.0),
+            Rotation::from_degrees(5.0),
+            Rotation::from_degrees(15.0)
+        ]
+    );
+}
+
+#[test]
+fn mirror_across_north_south() {
+    for axis in [Rotation::NORTH, Rotation::SOUTH] {
+        assert_eq!(Rotation::EAST.mirror_across(axis), Rotation::WEST);
+        assert_eq!(Rotation::WEST.mirror_across(axis), Rotation::EAST);
+        assert_eq!(Rotation::NORTH.mirror_across(axis), Rotation::NORTH);
+        assert_eq!(Rotation::SOUTH.mirror_across(axis), Rotation::SOUTH);
+        assert_eq!(Rotation::NORTHEAST.mirror_across(axis), Rotation::NORTHWEST);
+        assert_eq!(Rotation::SOUTHWEST.mirror_across(axis), Rotation::SOUTHEAST);
+        assert_eq!(
+            Rotation::from_degrees(10.0).mirror_across(axis),
+            Rotation::from_degrees(350.0)
+        );
+    }
+}
+
+#[test]
+fn mirror_across_east_west() {
+    for axis in [Rotation::EAST, Rotation::WEST] {
+        assert_eq!(Rotation::NORTH.mirror_across(axis), Rotation::SOUTH);
+        assert_eq!(Rotation::SOUTH.mirror_across(axis), Rotation::NORTH);
+        assert_eq!(Rotation::EAST.mirror_across(axis), Rotation::EAST);
+        assert_eq!(Rotation::WEST.mirror_across(axis), Rotation::WEST);
+        assert_eq!(Rotation::NORTHEAST.mirror_across(axis), Rotation::SOUTHEAST);
+        assert_eq!(Rotation::NORTHWEST.mirror_across(axis), Rotation::SOUTHWEST);
+        assert_eq!(
+            Rotation::from_degrees(10.0).mirror_across(axis),
+            Rotation::from_degrees(170.0)
+        );
+    }
+}
+
+#[test]
+fn mirror_is_an_involution() {
+    let axis = Rotation::from_degrees(33.3);
+
+    for deci_degrees in (0..Rotation::FULL_CIRCLE).step_by(7) {
+        let rotation = Rotation::new(deci_degrees);
+        assert_eq!(rotation.mirror_across(axis).mirror_across(axis), rotation);
+    }
+}
+
+#[test]
+fn clamp_to_max_step_below_cap() {
+    let max = Rotation::from_degrees(45.0);
+
+    assert_eq!(
+        Rotation::clamp_to_max_step(Rotation::from_degrees(20.0), max),
+        Rotation::from_degrees(20.0)
+    );
+    assert_eq!(
+        Rotation::clamp_to_max_step(Rotation::from_degrees(-20.0), max),
+        Rotation::from_degrees(-20.0)
+    );
+    assert_eq!(Rotation::clamp_to_max_step(max, max), max);
+    assert_eq!(Rotation::clamp_to_max_step(-max, max), -max);
+    assert_eq!(
+        Rotation::clamp_to_max_step(Rotation::NORTH, max),
+        Rotation::NORTH
+    );
+}
+
+#[test]
+fn clamp_to_max_step_above_cap() {
+    let max = Rotation::from_degrees(45.0);
+
+    // Clockwise turns
+    assert_eq!(Rotation::clamp_to_max_step(Rotation::new(451), max), max);
+    assert_eq!(Rotation::clamp_to_max_step(Rotation::EAST, max), max);
+
+    // Counterclockwise turns
+    assert_eq!(Rotation::clamp_to_max_step(Rotation::new(3149), max), -max);
+    assert_eq!(Rotation::clamp_to_max_step(Rotation::WEST, max), -max);
+
+    // Half turns are treated as clockwise, matching `rotation_direction`
+    assert_eq!(Rotation::clamp_to_max_step(Rotation::SOUTH, max), max);
+
+    // Caps of more than half a turn never limit anything
+    assert_eq!(
+        Rotation::clamp_to_max_step(Rotation::SOUTH, Rotation::from_degrees(270.0)),
+        Rotation::SOUTH
+    );
+}
+
+#[test]
+fn partial_ord_is_linear() {
+    // The derived ordering compares the stored angles as numbers, with a seam at north
+    assert!(Rotation::from_degrees(359.0) > Rotation::from_degrees(1.0));
+    assert!(Rotation::NORTH < Rotation::NORTHEAST);
+    assert!(Rotation::NORTHWEST > Rotation::SOUTH);
+
+    let mut rotations = vec![
+        Rotation::WEST,
+        Rotation::NORTH,
+        Rotation::SOUTH,
+        Rotation::EAST,
+    ];
+    rotations.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(
+        rotations,
+        vec![
+            Rotation::NORTH,
+            Rotation::EAST,
+            Rotation::SOUTH,
+            Rotation::WEST
+        ]
+    );
+}
+
+#[test]
+fn cyclic_cmp_from_origin() {
+    use core::cmp::Ordering;
+
+    let mut rotations = vec![
+        Rotation::WEST,
+        Rotation::NORTH,
+        Rotation::SOUTH,
+        Rotation::EAST,
+    ];
+    rotations.sort_by(|&a, &b| a.cyclic_cmp(b, Rotation::SOUTH));
+    assert_eq!(
+        rotations,
+        vec![
+            Rotation::SOUTH,
+            Rotation::WEST,
+            Rotation::NORTH,
+            Rotation::EAST
+        ]
+    );
+
+    // Matches the derived ordering when measured from north
+    for (a, b) in [
+        (Rotation::new(10), Rotation::new(3590)),
+        (Rotation::WEST, Rotation::EAST),
+    ] {
+        assert_eq!(a.cyclic_cmp(b, Rotation::NORTH), a.partial_cmp(&b).unwrap());
+    }
+
+    assert_eq!(
+        Rotation::EAST.cyclic_cmp(Rotation::EAST, Rotation::WEST),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn opposite_rotations() {
+    assert_eq!(Rotation::NORTH.opposite(), Rotation::SOUTH);
+    assert_eq!(Rotation::EAST.opposite(), Rotation::WEST);
+    assert_eq!(Rotation::SOUTH.opposite(), Rotation::NORTH);
+    assert_eq!(Rotation::new(3599).opposite(), Rotation::new(1799));
+
+    for deci_degrees in (0..Rotation::FULL_CIRCLE).step_by(7) {
+        let rotation = Rotation::new(deci_degrees);
+        assert_eq!(rotation.opposite().opposite(), rotation);
+    }
+}
+
+#[test]
+fn opposite_is_not_negation() {
+    // Negation reflects across the north-south axis instead
+    assert_eq!(-Rotation::EAST, Rotation::WEST);
+    assert_eq!(-Rotation::NORTH, Rotation::NORTH);
+    assert_eq!(-Rotation::SOUTH, Rotation::SOUTH);
+
+    assert_ne!(-Rotation::NORTH, Rotation::NORTH.opposite());
+}
+
+#[test]
+fn rotation_from_vec2_or() {
+    Rotation::from_vec2_or(Vec2::new(-2.0, -2.0), Rotation::NORTH)
+        .assert_approx_eq(Rotation::SOUTHWEST);
+    Rotation::from_vec2_or(Vec2::new(0.0, 3.0), Rotation::EAST).assert_approx_eq(Rotation::NORTH);
+
+    // Zero and nearly-zero vectors use the fallback
+    assert_eq!(
+        Rotation::from_vec2_or(Vec2::ZERO, Rotation::NORTH),
+        Rotation::NORTH
+    );
+    assert_eq!(
+        Rotation::from_vec2_or(Vec2::ZERO, Rotation::WEST),
+        Rotation::WEST
+    );
+    assert_eq!(
+        Rotation::from_vec2_or(Vec2::splat(f32::EPSILON / 4.0), Rotation::WEST),
+        Rotation::WEST
+    );
+}
+
+#[test]
+fn rotation_scaling_by_non_finite_values() {
+    // 0 / 0
+    assert_eq!(Rotation::NORTH / 0.0, Rotation::NORTH);
+    assert_eq!(0.0 / Rotation::NORTH, Rotation::NORTH);
+    // x / 0
+    assert_eq!(Rotation::EAST / 0.0, Rotation::NORTH);
+    assert_eq!(1.0 / Rotation::NORTH, Rotation::NORTH);
+
+    assert_eq!(Rotation::EAST * f32::NAN, Rotation::NORTH);
+    assert_eq!(f32::NAN * Rotation::EAST, Rotation::NORTH);
+    assert_eq!(Rotation::EAST * f32::INFINITY, Rotation::NORTH);
+    assert_eq!(Rotation::EAST / f32::NAN, Rotation::NORTH);
+}
+
+#[test]
+fn rotation_from_tiny_negative_values() {
+    assert!(Rotation::from_degrees(-1e-6).deci_degrees() < Rotation::FULL_CIRCLE);
+    assert!(Rotation::from_radians(-1e-9).deci_degrees() < Rotation::FULL_CIRCLE);
+}
+
+const STEEP_SLOPE: Direction = Direction::new_unchecked(const_vec2!([0.6, 0.8]));
+const DUE_WEST: Direction = Direction::new_unchecked(const_vec2!([-1.0, 0.0]));
+
+#[test]
+fn new_unchecked_constants() {
+    assert_eq!(DUE_WEST, Direction::WEST);
+    Rotation::from(DUE_WEST).assert_approx_eq(Rotation::WEST);
+
+    // atan(0.6 / 0.8) is about 36.87 degrees clockwise from north
+    Rotation::from(STEEP_SLOPE).assert_approx_eq(Rotation::new(369));
+    STEEP_SLOPE.assert_approx_eq(Direction::new(Vec2::new(3.0, 4.0)));
+}
+
+#[test]
+fn bisect_without_wrapping() {
+    assert_eq!(Rotation::NORTH.bisect(Rotation::EAST), Rotation::NORTHEAST);
+    assert_eq!(Rotation::EAST.bisect(Rotation::NORTH), Rotation::NORTHEAST);
+    assert_eq!(
+        Rotation::new(1000).bisect(Rotation::new(1400)),
+        Rotation::new(1200)
+    );
+    assert_eq!(Rotation::WEST.bisect(Rotation::WEST), Rotation::WEST);
+
+    // Odd arcs round towards `self`
+    assert_eq!(
+        Rotation::new(10).bisect(Rotation::new(13)),
+        Rotation::new(11)
+    );
+    assert_eq!(
+        Rotation::new(13).bisect(Rotation::new(10)),
+        Rotation::new(12)
+    );
+}
+
+#[test]
+fn bisect_across_north() {
+    let before_north = Rotation::from_degrees(350.0);
+    let after_north = Rotation::from_degrees(10.0);
+
+    assert_eq!(before_north.bisect(after_north), Rotation::NORTH);
+    assert_eq!(after_north.bisect(before_north), Rotation::NORTH);
+
+    // The short arc between 300° and 80° passes through north, not south
+    assert_eq!(
+        Rotation::from_degrees(300.0).bisect(Rotation::from_degrees(80.0)),
+        Rotation::from_degrees(10.0)
+    );
+    assert_eq!(
+        Rotation::NORTHWEST.bisect(Rotation::NORTHEAST),
+        Rotation::NORTH
+    );
+}
+
+#[test]
+fn bisect_opposite_rotations() {
+    assert_eq!(Rotation::NORTH.bisect(Rotation::SOUTH), Rotation::EAST);
+    assert_eq!(Rotation::SOUTH.bisect(Rotation::NORTH), Rotation::WEST);
+}
+
+#[test]
+fn nearly_equal_directions_hash_together() {
+    let mut directions = HashSet::new();
+
+    directions.insert(Direction::EAST);
+    directions.insert(Direction::new(Vec2::new(1.0, 1e-5)));
+    directions.insert(Direction::new(Vec2::new(1.0, -1e-5)));
+    directions.insert(Direction::from(Rotation::EAST));
+    assert_eq!(directions.len(), 1);
+
+    // Directions a full deci-degree apart are distinct
+    directions.insert(Direction::from(Rotation::new(901)));
+    assert_eq!(directions.len(), 2);
+
+    // Wrapping around north does not produce a separate entry
+    directions.insert(Direction::NORTH);
+    directions.insert(Direction::new(Vec2::new(-1e-5, 1.0)));
+    assert_eq!(directions.len(), 3);
+}
+
+#[test]
+fn neutral_direction_hashes_separately() {
+    let mut directions = HashSet::new();
+
+    directions.insert(Direction::NEUTRAL);
+    directions.insert(Direction::NORTH);
+    directions.insert(Direction::NEUTRAL);
+
+    assert_eq!(directions.len(), 2);
+    assert_eq!(Direction::NEUTRAL, Direction::NEUTRAL);
+    assert_ne!(Direction::NEUTRAL, Direction::NORTH);
+}
+
+#[test]
+fn steer_direction_towards_target() {
+    let mut direction = Direction::NORTH;
+    let max_rotation = Rotation::from_degrees(30.0);
+
+    assert!(!direction.rotate_towards(Direction::EAST, Some(max_rotation)));
+    direction.assert_approx_eq(Direction::new(Vec2::new(0.5, 0.75_f32.sqrt())));
+    assert!((direction.unit_vector().length() - 1.0).abs() < 1e-6);
+
+    assert!(!direction.rotate_towards(Direction::EAST, Some(max_rotation)));
+    direction.assert_approx_eq(Rotation::from_degrees(60.0));
+
+    // The final step lands exactly on the target
+    assert!(direction.rotate_towards(Direction::EAST, Some(max_rotation)));
+    assert_eq!(direction.unit_vector(), Direction::EAST.unit_vector());
+
+    // Counterclockwise steering works too
+    assert!(!direction.rotate_towards(Direction::NORTH, Some(max_rotation)));
+    direction.assert_approx_eq(Rotation::from_degrees(60.0));
+}
+
+#[test]
+fn steering_preserves_unit_length() {
+    let mut direction = Direction::new(Vec2::new(0.3, -0.7));
+    let target = Direction::new(Vec2::new(-0.2, 0.9));
+
+    for _ in 0..300 {
+        direction.rotate_towards(target, Some(Rotation::new(7)));
+        assert!((direction.unit_vector().length() - 1.0).abs() < 1e-5);
+    }
+
+    assert_eq!(direction, target);
+}
+
+#[test]
+fn steering_neutral_directions() {
+    let max_rotation = Some(Rotation::from_degrees(30.0));
+
+    let mut neutral = Direction::NEUTRAL;
+    assert!(!neutral.rotate_towards(Direction::EAST, max_rotation));
+    assert!(neutral.is_neutral());
+
+    let mut direction = Direction::SOUTH;
+    assert!(!direction.rotate_towards(Direction::NEUTRAL, max_rotation));
+    assert!(!direction.rotate_towards(Direction::NEUTRAL, None));
+    assert_eq!(direction.unit_vector(), Direction::SOUTH.unit_vector());
+}
+
+#[test]
+fn saturating_add_stops_at_max() {
+    let max = Rotation::new(3500);
+
+    assert_eq!(
+        Rotation::new(1000).saturating_add(Rotation::new(500), max),
+        Rotation::new(1500)
+    );
+
+    // Ordinary addition would wrap past north
+    assert_eq!(Rotation::new(3400) + Rotation::new(300), Rotation::new(100));
+    assert_eq!(
+        Rotation::new(3400).saturating_add(Rotation::new(300), max),
+        max
+    );
+    assert_eq!(
+        Rotation::new(3599).saturating_add(Rotation::new(3599), Rotation::new(3599)),
+        Rotation::new(3599)
+    );
+
+    // Values already past the limit are clamped
+    assert_eq!(
+        Rotation::SOUTH.saturating_add(Rotation::NORTH, Rotation::EAST),
+        Rotation::EAST
+    );
+}
+
+#[test]
+fn saturating_sub_stops_at_min() {
+    let min = Rotation::new(100);
+
+    assert_eq!(
+        Rotation::new(1000).saturating_sub(Rotation::new(500), min),
+        Rotation::new(500)
+    );
+
+    // Ordinary subtraction would wrap past north
+    assert_eq!(Rotation::new(200) - Rotation::new(300), Rotation::new(3500));
+    assert_eq!(
+        Rotation::new(200).saturating_sub(Rotation::new(300), min),
+        min
+    );
+    assert_eq!(
+        Rotation::NORTH.saturating_sub(Rotation::new(3599), Rotation::NORTH),
+        Rotation::NORTH
+    );
+}
+
+#[test]
+fn slerp_interpolates_angle_uniformly() {
+    assert_eq!(
+        Direction::NORTH.slerp(Direction::EAST, 0.5),
+        Direction::NORTHEAST
+    );
+    assert_eq!(
+        Direction::NORTH.slerp(Direction::EAST, 0.0),
+        Direction::NORTH
+    );
+    assert_eq!(
+        Direction::NORTH.slerp(Direction::EAST, 1.0),
+        Direction::EAST
+    );
+
+    // Additive blending is biased towards the middle of the arc, but slerp is not
+    let additive = Direction::new(
+        Direction::NORTH.unit_vector() * 0.75 + Direction::EAST.unit_vector() * 0.25,
+    );
+    Rotation::from(additive).assert_approx_eq(Rotation::from_degrees(18.4));
+    Direction::NORTH
+        .slerp(Direction::EAST, 0.25)
+        .assert_approx_eq(Rotation::from_degrees(22.5));
+
+    for step in 0..=10 {
+        let s = step as f32 / 10.0;
+        Direction::SOUTH
+            .slerp(Direction::WEST, s)
+            .assert_approx_eq(Rotation::from_degrees(180.0 + 90.0 * s));
+    }
+}
+
+#[test]
+fn slerp_takes_the_short_way_around() {
+    // North is crossed, rather than passing through south
+    let before_north = Direction::from(Rotation::from_degrees(340.0));
+    let after_north = Direction::from(Rotation::from_degrees(20.0));
+
+    before_north
+        .slerp(after_north, 0.5)
+        .assert_approx_eq(Direction::NORTH);
+    after_north
+        .slerp(before_north, 0.25)
+        .assert_approx_eq(Rotation::from_degrees(10.0));
+
+    // Nearly opposite directions stay on the shorter arc, even though their sum is tiny
+    let slightly_west_of_south = Direction::from(Rotation::from_degrees(181.0));
+    Direction::NORTH
+        .slerp(slightly_west_of_south, 0.5)
+        .assert_approx_eq(Rotation::from_degrees(270.5));
+}
+
+#[test]
+fn slerp_edge_cases() {
+    // Exactly opposite directions pick the clockwise arc
+    Direction::NORTH
+        .slerp(Direction::SOUTH, 0.5)
+        .assert_approx_eq(Direction::EAST);
+    Direction::EAST
+        .slerp(Direction::WEST, 0.5)
+        .assert_approx_eq(Direction::SOUTH);
+
+    assert_eq!(
+        Direction::NORTH.slerp(Direction::NEUTRAL, 0.5),
+        Direction::NEUTRAL
+    );
+    assert_eq!(
+        Direction::NEUTRAL.slerp(Direction::NORTH, 0.5),
+        Direction::NEUTRAL
+    );
+
+    // The result is always a unit vector
+    let direction =
+        Direction::new(Vec2::new(0.3, -0.8)).slerp(Direction::new(Vec2::new(-2.0, 0.1)), 0.37);
+    assert!((direction.unit_vector().length() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn project_and_reject_northeast() {
+    let northeast = Direction::NORTHEAST.unit_vector();
+
+    assert_eq!(
+        Direction::NORTHEAST.project_onto(Direction::EAST),
+        Vec2::new(northeast.x, 0.0)
+    );
+    assert_eq!(
+        Direction::NORTHEAST.reject_from(Direction::EAST),
+        Vec2::new(0.0, northeast.y)
+    );
+
+    // The axis can point either way along the line
+    assert_eq!(
+        Direction::NORTHEAST.project_onto(Direction::WEST),
+        Vec2::new(northeast.x, 0.0)
+    );
+}
+
+#[test]
+fn project_and_reject_sum_to_unit_vector() {
+    let direction = Direction::new(Vec2::new(0.3, -0.8));
+    let axis = Direction::new(Vec2::new(-2.0, 0.7));
+
+    let projection = direction.project_onto(axis);
+    let rejection = direction.reject_from(axis);
+
+    assert!((projection + rejection - direction.unit_vector()).length() < 1e-6);
+    assert!(projection.dot(rejection).abs() < 1e-6);
+}
+
+#[test]
+fn project_and_reject_neutral() {
+    assert_eq!(Direction::NEUTRAL.project_onto(Direction::EAST), Vec2::ZERO);
+    assert_eq!(Direction::NEUTRAL.reject_from(Direction::EAST), Vec2::ZERO);
+    assert_eq!(
+        Direction::NORTH.project_onto(Direction::NEUTRAL),
+        Vec2::ZERO
+    );
+    assert_eq!(Direction::NORTH.reject_from(Direction::NEUTRAL), Vec2::ZERO);
+}
+
+#[test]
+fn enclosing_arc_clustered() {
+    let rotations = [
+        Rotation::from_degrees(100.0),
+        Rotation::from_degrees(130.0),
+        Rotation::from_degrees(110.0),
+    ];
+
+    assert_eq!(
+        Rotation::enclosing_arc(&rotations),
+        Some((Rotation::from_degrees(115.0), Rotation::from_degrees(15.0)))
+    );
+
+    // A single rotation is enclosed by an arc of no width
+    assert_eq!(
+        Rotation::enclosing_arc(&[Rotation::WEST, Rotation::WEST]),
+        Some((Rotation::WEST, Rotation::new(0)))
+    );
+
+    // Odd-width arcs are rounded outwards
+    assert_eq!(
+        Rotation::enclosing_arc(&[Rotation::new(10), Rotation::new(13)]),
+        Some((Rotation::new(11), Rotation::new(2)))
+    );
+}
+
+#[test]
+fn enclosing_arc_straddling_north() {
+    let rotations = [
+        Rotation::from_degrees(10.0),
+        Rotation::from_degrees(340.0),
+        Rotation::from_degrees(30.0),
+        Rotation::from_degrees(355.0),
+    ];
+    let (center, half_width) = Rotation::enclosing_arc(&rotations).unwrap();
+
+    assert_eq!(center, Rotation::from_degrees(5.0));
+    assert_eq!(half_width, Rotation::from_degrees(25.0));
+
+    for rotation in rotations {
+        assert!(rotation.distance(center) <= half_width);
+    }
+}
+
+#[test]
+fn enclosing_arc_wide_spread() {
+    // The largest gap is between east and west, so the arc runs clockwise from west to east
+    let rotations = [Rotation::NORTH, Rotation::EAST, Rotation::WEST];
+    let (center, half_width) = Rotation::enclosing_arc(&rotations).unwrap();
+
+    assert_eq!(center, Rotation::NORTH);
+    assert_eq!(half_width, Rotation::EAST);
+
+    // Gaps on either side of north
+    let rotations = [
+        Rotation::new(1000),
+        Rotation::new(2000),
+        Rotation::new(2500),
+    ];
+    let (center, half_width) = Rotation::enclosing_arc(&rotations).unwrap();
+
+    assert_eq!(center, Rotation::new(1750));
+    assert_eq!(half_width, Rotation::new(750));
+
+    for rotation in rotations {
+        assert!(rotation.distance(center) <= half_width);
+    }
+}
+
+#[test]
+fn enclosing_arc_empty() {
+    assert_eq!(Rotation::enclosing_arc(&[]), None);
+}
+
+#[test]
+fn average_of_identical_directions() {
+    assert_eq!(Direction::average([Direction::NORTH; 5]), Direction::NORTH);
+}
+
+#[test]
+fn average_of_opposing_directions_is_neutral() {
+    assert_eq!(
+        Direction::average([Direction::NORTH, Direction::SOUTH]),
+        Direction::NEUTRAL
+    );
+    assert_eq!(
+        Direction::average([Direction::NORTHEAST, Direction::SOUTHWEST]),
+        Direction::NEUTRAL
+    );
+}
+
+#[test]
+fn average_ignores_neutral_directions() {
+    assert_eq!(
+        Direction::average([Direction::WEST, Direction::NEUTRAL]),
+        Direction::WEST
+    );
+    assert_eq!(Direction::average([Direction::NEUTRAL]), Direction::NEUTRAL);
+}
+
+#[test]
+fn checked_arithmetic_small_steps() {
+    let rotation = Rotation::from_degrees(90.0);
+
+    assert_eq!(rotation.add_checked(Rotation::SOUTH), Rotation::WEST);
+    assert_eq!(rotation.sub_checked(Rotation::SOUTH), Rotation::WEST);
+    assert_eq!(
+        rotation.add_checked(Rotation::from_degrees(10.0)),
+        rotation + Rotation::from_degrees(10.0)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn add_checked_large_step() {
+    let _ = Rotation::NORTH.add_checked(Rotation::from_degrees(200.0));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn sub_checked_large_step() {
+    let _ = Rotation::NORTH.sub_checked(Rotation::from_degrees(200.0));
+}
+
+#[test]
+fn gaps_between_rotations() {
+    let rotations = [
+        Rotation::from_degrees(0.0),
+        Rotation::from_degrees(90.0),
+        Rotation::from_degrees(180.0),
+    ];
+
+    assert_eq!(
+        Rotation::gaps(&rotations),
+        vec![
+            Rotation::from_degrees(90.0),
+            Rotation::from_degrees(90.0),
+            Rotation::from_degrees(180.0),
+        ]
+    );
+}
+
+#[test]
+fn gaps_edge_cases() {
+    assert_eq!(Rotation::gaps(&[]), vec![]);
+    assert_eq!(Rotation::gaps(&[Rotation::EAST]), vec![Rotation::NORTH]);
+
+    // The wrapping gap crosses north
+    let rotations = [Rotation::from_degrees(30.0), Rotation::from_degrees(300.0)];
+    assert_eq!(
+        Rotation::gaps(&rotations),
+        vec![Rotation::from_degrees(270.0), Rotation::from_degrees(90.0)]
+    );
+}
+
+#[test]
+fn quantize_to_even_steps() {
+    let step = Rotation::from_degrees(15.0);
+
+    assert_eq!(Rotation::NORTH.quantize(step), Rotation::NORTH);
+    assert_eq!(
+        Rotation::from_degrees(50.0).quantize(step),
+        Rotation::from_degrees(45.0)
+    );
+    assert_eq!(
+        Rotation::from_degrees(53.0).quantize(step),
+        Rotation::from_degrees(60.0)
+    );
+    // Ties round clockwise
+    assert_eq!(
+        Rotation::new(525).quantize(step),
+        Rotation::from_degrees(60.0)
+    );
+    assert_eq!(
+        Rotation::from_degrees(352.0).quantize(step),
+        Rotation::from_degrees(345.0)
+    );
+    assert_eq!(
+        Rotation::from_degrees(353.0).quantize(step),
+        Rotation::NORTH
+    );
+}
+
+#[test]
+fn quantize_to_uneven_steps() {
+    // 7 degrees does not divide the circle: the largest multiple is 357 degrees
+    let step = Rotation::from_degrees(7.0);
+
+    assert_eq!(
+        Rotation::from_degrees(10.0).quantize(step),
+        Rotation::from_degrees(7.0)
+    );
+    assert_eq!(
+        Rotation::from_degrees(11.0).quantize(step),
+        Rotation::from_degrees(14.0)
+    );
+    // The last bin is only 3 degrees wide
+    assert_eq!(
+        Rotation::from_degrees(358.0).quantize(step),
+        Rotation::from_degrees(357.0)
+    );
+    assert_eq!(Rotation::new(3585).quantize(step), Rotation::NORTH);
+    assert_eq!(
+        Rotation::from_degrees(359.0).quantize(step),
+        Rotation::NORTH
+    );
+}
+
+#[test]
+fn quantize_to_zero_step() {
+    let rotation = Rotation::new(1234);
+    assert_eq!(rotation.quantize(Rotation::NORTH), rotation);
+}
+
+#[test]
+fn signed_deci_degrees_wrap() {
+    assert_eq!(Rotation::from_signed_deci_degrees(0), Rotation::NORTH);
+    assert_eq!(Rotation::from_signed_deci_degrees(-1), Rotation::new(3599));
+    assert_eq!(Rotation::from_signed_deci_degrees(-3600), Rotation::NORTH);
+    assert_eq!(
+        Rotation::from_signed_deci_degrees(-3601),
+        Rotation::new(3599)
+    );
+    assert_eq!(Rotation::from_signed_deci_degrees(3600), Rotation::NORTH);
+    assert_eq!(
+        Rotation::from_signed_deci_degrees(i16::MIN),
+        Rotation::new((i16::MIN as i32).rem_euclid(3600) as u16)
+    );
+}
+
+#[test]
+fn signed_deci_degrees_boundary() {
+    assert_eq!(Rotation::new(1799).signed_deci_degrees(), 1799);
+    assert_eq!(Rotation::SOUTH.signed_deci_degrees(), 1800);
+    assert_eq!(Rotation::new(1801).signed_deci_degrees(), -1799);
+    assert_eq!(Rotation::new(3599).signed_deci_degrees(), -1);
+    assert_eq!(Rotation::from_signed_deci_degrees(-1800), Rotation::SOUTH);
+
+    // Round trips for every rotation
+    for deci_degrees in 0..Rotation::FULL_CIRCLE {
+        let rotation = Rotation::new(deci_degrees);
+        let signed = rotation.signed_deci_degrees();
+        assert!(signed > -1800 && signed <= 1800);
+        assert_eq!(Rotation::from_signed_deci_degrees(signed), rotation);
+    }
+}
+
+#[test]
+fn rotate_vec2_preserves_magnitude() {
+    let rotated = Rotation::EAST.rotate_vec2(Vec2::new(2.0, 0.0));
+    assert!(rotated.abs_diff_eq(Vec2::new(0.0, -2.0), 1e-5));
+
+    let rotated = Rotation::from_degrees(30.0).rotate_vec2(Vec2::new(3.0, 4.0));
+    assert!((rotated.length() - 5.0).abs() < 1e-5);
+
+    assert_eq!(Rotation::SOUTH.rotate_vec2(Vec2::ZERO), Vec2::ZERO);
+}
+
+#[test]
+fn singular_conversions_agree() {
+    use leafwing_2d::orientation::SINGULARITY_EPSILON;
+
+    let tiny = Vec2::new(SINGULARITY_EPSILON * 0.5, 0.0);
+    assert!(Rotation::try_from(tiny).is_err());
+    assert!(Direction::try_from(tiny).is_err());
+    assert_eq!(
+        Direction::from_vec2_with_magnitude(tiny),
+        (Direction::NEUTRAL, 0.0)
+    );
+
+    let small = Vec2::new(SINGULARITY_EPSILON * 2.0, 0.0);
+    Rotation::try_from(small)
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+    Direction::try_from(small)
+        .unwrap()
+        .assert_approx_eq(Direction::EAST);
+    assert_eq!(
+        Direction::from_vec2_with_magnitude(small).0,
+        Direction::EAST
+    );
+}
+
+#[test]
+#[should_panic]
+fn direction_new_panics_on_tiny_vector() {
+    let _ = Direction::new(Vec2::new(0.0, f32::EPSILON * 0.5));
+}
+
+#[test]
+fn direction_then_matches_rotation_addition() {
+    for first in 0..36 {
+        for second in 0..36 {
+            let first = Rotation::new(first * 100);
+            let second = Rotation::new(second * 100);
+
+            let composed = Direction::from(first).then(Direction::from(second));
+            composed.assert_approx_eq(Direction::from(first + second));
+            assert!((composed.unit_vector().length() - 1.0).abs() < 1e-5);
+        }
+    }
+
+    Direction::EAST
+        .then(Direction::NORTHEAST)
+        .assert_approx_eq(Direction::from(Rotation::from_degrees(135.0)));
+}
+
+#[test]
+fn direction_then_neutral() {
+    assert_eq!(
+        Direction::SOUTHWEST.then(Direction::NEUTRAL),
+        Direction::SOUTHWEST
+    );
+    assert_eq!(
+        Direction::NEUTRAL.then(Direction::NORTHEAST),
+        Direction::NEUTRAL
+    );
+    assert_eq!(
+        Direction::NEUTRAL.then(Direction::NEUTRAL),
+        Direction::NEUTRAL
+    );
+}
+
+#[test]
+fn path_crosses_dead_zone() {
+    let dead_zone = Rotation::SOUTH;
+    let half_width = Rotation::from_degrees(30.0);
+
+    // Short paths through the dead zone, in both directions
+    assert!(Rotation::EAST.path_crosses(Rotation::WEST - Rotation::new(10), dead_zone, half_width));
+    assert!(Rotation::from_degrees(200.0).path_crosses(
+        Rotation::from_degrees(120.0),
+        dead_zone,
+        half_width
+    ));
+    // Ending inside the dead zone
+    assert!(Rotation::EAST.path_crosses(Rotation::from_degrees(160.0), dead_zone, half_width));
+    // Touching the edge of the dead zone
+    assert!(Rotation::EAST.path_crosses(Rotation::from_degrees(150.0), dead_zone, half_width));
+
+    // Short paths that avoid the dead zone
+    assert!(!Rotation::EAST.path_crosses(
+        Rotation::WEST + Rotation::new(10),
+        dead_zone,
+        half_width
+    ));
+    assert!(!Rotation::EAST.path_crosses(Rotation::from_degrees(149.0), dead_zone, half_width));
+    assert!(!Rotation::NORTHWEST.path_crosses(Rotation::NORTHEAST, dead_zone, half_width));
+    assert!(!Rotation::NORTH.path_crosses(Rotation::NORTH, dead_zone, half_width));
+}
+
+#[test]
+fn path_crosses_dead_zone_around_midnight() {
+    let dead_zone = Rotation::NORTH;
+    let half_width = Rotation::from_degrees(10.0);
+
+    assert!(Rotation::from_degrees(340.0).path_crosses(
+        Rotation::from_degrees(20.0),
+        dead_zone,
+        half_width
+    ));
+    assert!(Rotation::from_degrees(5.0).path_crosses(
+        Rotation::from_degrees(90.0),
+        dead_zone,
+        half_width
+    ));
+    assert!(!Rotation::from_degrees(20.0).path_crosses(
+        Rotation::from_degrees(170.0),
+        dead_zone,
+        half_width
+    ));
+    assert!(!Rotation::from_degrees(340.0).path_crosses(
+        Rotation::from_degrees(200.0),
+        dead_zone,
+        half_width
+    ));
+
+    // A forbidden arc covering the whole circle is always crossed
+    assert!(Rotation::EAST.path_crosses(Rotation::EAST, Rotation::WEST, Rotation::SOUTH));
+}
+
+#[test]
+fn subdivide_wraps_around_midnight() {
+    let arc: Vec<Rotation> = Rotation::subdivide(
+        Rotation::from_degrees(350.0),
+        Rotation::from_degrees(10.0),
+        2,
+    )
+    .collect();
+    assert_eq!(
+        arc,
+        vec![
+            Rotation::from_degrees(350.0),
+            Rotation::NORTH,
+            Rotation::from_degrees(10.0)
+        ]
+    );
+
+    // Counterclockwise arcs are traced counterclockwise
+    let arc: Vec<Rotation> = Rotation::subdivide(
+        Rotation::from_degrees(10.0),
+        Rotation::from_degrees(350.0),
+        4,
+    )
+    .collect();
+    assert_eq!(
+        arc,
+        vec![
+            Rotation::from_degrees(10.0),
+            Rotation::from_degrees(5.0),
+            Rotation::NORTH,
+            Rotation::from_degrees(355.0),
+            Rotation::from_degrees(350.0),
+        ]
+    );
+}
+
+#[test]
+fn subdivide_edge_cases() {
+    assert_eq!(
+        Rotation::subdivide(Rotation::EAST, Rotation::WEST, 0).collect::<Vec<_>>(),
+        vec![Rotation::EAST]
+    );
+    assert_eq!(
+        Rotation::subdivide(Rotation::EAST, Rotation::WEST, 1).collect::<Vec<_>>(),
+        vec![Rotation::EAST, Rotation::WEST]
+    );
+    // Opposite rotations are traced clockwise
+    assert_eq!(
+        Rotation::subdivide(Rotation::EAST, Rotation::WEST, 2).collect::<Vec<_>>(),
+        vec![Rotation::EAST, Rotation::SOUTH, Rotation::WEST]
+    );
+    // Identical endpoints
+    assert_eq!(
+        Rotation::subdivide(Rotation::SOUTH, Rotation::SOUTH, 3).collect::<Vec<_>>(),
+        vec![Rotation::SOUTH; 4]
+    );
+
+    // The last rotation is always exactly `to`, even when the steps do not divide the arc evenly
+    let arc: Vec<Rotation> = Rotation::subdivide(Rotation::NORTH, Rotation::new(100), 3).collect();
+    assert_eq!(
+        arc,
+        vec![
+            Rotation::NORTH,
+            Rotation::new(33),
+            Rotation::new(67),
+            Rotation::new(100)
+        ]
+    );
+}
+
+#[test]
+fn into_vec2_with_magnitude() {
+    let velocity = Rotation::EAST.into_vec2_with_magnitude(5.0);
+    assert!(velocity.abs_diff_eq(Vec2::new(5.0, 0.0), 1e-5));
+
+    let velocity = Rotation::NORTHWEST.into_vec2_with_magnitude(2.0);
+    assert!((velocity.length() - 2.0).abs() < 1e-5);
+    assert_eq!(Rotation::from_vec2(velocity), Ok(Rotation::NORTHWEST));
+
+    assert_eq!(Rotation::WEST.into_vec2_with_magnitude(0.0), Vec2::ZERO);
+}
+
+#[test]
+fn clamp_to_cone() {
+    let half_angle = Rotation::from_degrees(30.0);
+    let axis = Direction::EAST;
+
+    // Inside the cone, including on its edge
+    let inside = Direction::from(Rotation::from_degrees(110.0));
+    assert_eq!(inside.clamp_to_cone(axis, half_angle), inside);
+    let edge = Direction::from(Rotation::from_degrees(60.0));
+    assert_eq!(edge.clamp_to_cone(axis, half_angle), edge);
+
+    // Outside the cone, on either side
+    let clamped = Direction::SOUTH.clamp_to_cone(axis, half_angle);
+    clamped.assert_approx_eq(Direction::from(Rotation::from_degrees(120.0)));
+    assert!((clamped.unit_vector().length() - 1.0).abs() < 1e-5);
+
+    Direction::NORTH
+        .clamp_to_cone(axis, half_angle)
+        .assert_approx_eq(Direction::from(Rotation::from_degrees(60.0)));
+
+    // Directly behind the axis snaps clockwise
+    Direction::WEST
+        .clamp_to_cone(axis, half_angle)
+        .assert_approx_eq(Direction::from(Rotation::from_degrees(120.0)));
+
+    // The cone wraps around midnight
+    Direction::from(Rotation::from_degrees(300.0))
+        .clamp_to_cone(Direction::NORTH, half_angle)
+        .assert_approx_eq(Direction::from(Rotation::from_degrees(330.0)));
+}
+
+#[test]
+fn clamp_to_cone_neutral() {
+    let half_angle = Rotation::from_degrees(30.0);
+
+    assert_eq!(
+        Direction::NEUTRAL.clamp_to_cone(Direction::NORTH, half_angle),
+        Direction::NEUTRAL
+    );
+    assert_eq!(
+        Direction::SOUTH.clamp_to_cone(Direction::NEUTRAL, half_angle),
+        Direction::SOUTH
+    );
+}
+
+#[test]
+fn reflex_distance_completes_the_circle() {
+    let pairs = [
+        (Rotation::NORTH, Rotation::EAST),
+        (Rotation::EAST, Rotation::NORTH),
+        (Rotation::from_degrees(350.0), Rotation::from_degrees(10.0)),
+        (Rotation::new(1), Rotation::new(3599)),
+        (Rotation::NORTHEAST, Rotation::SOUTH),
+        (Rotation::WEST, Rotation::EAST),
+    ];
+
+    for (a, b) in pairs {
+        let short = a.distance(b);
+        let long = a.reflex_distance(b);
+
+        assert_eq!(
+            short.deci_degrees() + long.deci_degrees(),
+            Rotation::FULL_CIRCLE,
+            "{a} and {b}"
+        );
+        assert!(long >= short);
+        assert_eq!(long, b.reflex_distance(a));
+    }
+
+    // A full turn wraps around to zero
+    assert_eq!(
+        Rotation::EAST.reflex_distance(Rotation::EAST),
+        Rotation::NORTH
+    );
+}
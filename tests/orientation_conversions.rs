@@ -1,4 +1,4 @@
-use bevy::math::Quat;
+use bevy::math::{Mat2, Quat, Vec2};
 use leafwing_2d::continuous::F32;
 use leafwing_2d::orientation::*;
 use leafwing_2d::position::Position;
@@ -164,3 +164,64 @@ fn holistic_conversions() {
     assert_conversions_match(Position::new(47.8, 0.03));
     assert_conversions_match(Position::new(-4001.0, 432.7));
 }
+
+#[test]
+fn rotation_to_mat2_matches_vec2() {
+    let basis_vectors = [Vec2::X, Vec2::Y, -Vec2::X, -Vec2::Y, Vec2::new(3.0, -4.0)];
+
+    for deci_degrees in (0..Rotation::FULL_CIRCLE).step_by(75) {
+        let rotation = Rotation::new(deci_degrees);
+        let matrix: Mat2 = rotation.into();
+        assert_eq!(matrix, rotation.to_mat2());
+
+        for vec in basis_vectors {
+            // Rotate the vector by adding its angle to `rotation`, then restore its length
+            let expected =
+                (Rotation::from_vec2(vec).unwrap() + rotation).into_vec2() * vec.length();
+            let actual = matrix * vec;
+
+            assert!(
+                actual.abs_diff_eq(expected, 0.02),
+                "{rotation} applied to {vec}: expected {expected}, got {actual}"
+            );
+        }
+    }
+}
+
+#[test]
+fn math_radians_convention() {
+    use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    // Counterclockwise from east
+    Rotation::from_math_radians(0.0).assert_approx_eq(Rotation::EAST);
+    Rotation::from_math_radians(FRAC_PI_4).assert_approx_eq(Rotation::NORTHEAST);
+    Rotation::from_math_radians(FRAC_PI_2).assert_approx_eq(Rotation::NORTH);
+    Rotation::from_math_radians(-FRAC_PI_2).assert_approx_eq(Rotation::SOUTH);
+
+    // Agrees with `atan2` on the unit vector
+    for deci_degrees in (0..Rotation::FULL_CIRCLE).step_by(45) {
+        let rotation = Rotation::new(deci_degrees);
+        let vec = rotation.into_vec2();
+        Rotation::from_math_radians(vec.y.atan2(vec.x)).assert_approx_eq(rotation);
+    }
+
+    assert!((Rotation::SOUTH.into_math_radians() - 3.0 * FRAC_PI_2).abs() < 1e-5);
+    assert!((Rotation::WEST.into_math_radians() - PI).abs() < 1e-5);
+}
+
+#[test]
+fn math_radians_round_trip() {
+    use core::f32::consts::FRAC_PI_2;
+
+    for deci_degrees in (0..Rotation::FULL_CIRCLE).step_by(15) {
+        let rotation = Rotation::new(deci_degrees);
+
+        let math_radians = rotation.into_math_radians();
+        assert!((0.0..core::f32::consts::TAU).contains(&math_radians));
+        Rotation::from_math_radians(math_radians).assert_approx_eq(rotation);
+
+        // The two conventions are reflections of each other across the northeast diagonal
+        Rotation::from_radians(FRAC_PI_2 - math_radians).assert_approx_eq(rotation);
+        Rotation::from_math_radians(FRAC_PI_2 - rotation.into_radians()).assert_approx_eq(rotation);
+    }
+}
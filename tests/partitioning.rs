@@ -0,0 +1,315 @@
+use bevy::math::Vec2;
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+use leafwing_2d::partitioning::{
+    CardinalHexadecant, CardinalOctant, CardinalQuadrant, DirectionParitioning, DirectionalSprites,
+};
+use leafwing_2d::position::Positionlike;
+
+#[derive(DirectionParitioning, Clone, Copy, Debug, PartialEq, Eq)]
+enum Tripod {
+    #[partition(degrees = 0.0)]
+    Up,
+    #[partition(degrees = 120.0)]
+    DownRight,
+    #[partition(degrees = 240)]
+    DownLeft,
+}
+
+#[test]
+fn derived_partitions() {
+    assert_eq!(
+        Tripod::partitions(),
+        vec![Tripod::Up, Tripod::DownRight, Tripod::DownLeft]
+    );
+}
+
+#[test]
+fn derived_conversions() {
+    assert_eq!(Rotation::from(Tripod::Up), Rotation::NORTH);
+    assert_eq!(Rotation::from(Tripod::DownRight), Rotation::new(1200));
+    assert_eq!(Rotation::from(Tripod::DownLeft), Rotation::new(2400));
+
+    Direction::from(Tripod::Up).assert_approx_eq(Direction::NORTH);
+    Direction::from(Tripod::DownLeft).assert_approx_eq(Rotation::new(2400));
+
+    Vec2::from(Tripod::Up).assert_approx_eq(Vec2::new(0.0, 1.0));
+}
+
+#[test]
+fn derived_snapping() {
+    assert_eq!(Tripod::snap(Rotation::from_degrees(30.0)), Tripod::Up);
+    assert_eq!(
+        Tripod::snap(Rotation::from_degrees(100.0)),
+        Tripod::DownRight
+    );
+    assert_eq!(
+        Tripod::snap(Rotation::from_degrees(200.0)),
+        Tripod::DownLeft
+    );
+    assert_eq!(Tripod::snap(Rotation::from_degrees(330.0)), Tripod::Up);
+
+    Tripod::snap_direction(Direction::SOUTHWEST).assert_approx_eq(Rotation::new(2400));
+}
+
+#[test]
+fn snap_with_fraction_at_partition_centers() {
+    for partition in CardinalOctant::partitions() {
+        assert_eq!(
+            CardinalOctant::snap_with_fraction(Rotation::from(partition)),
+            (partition, 0.0)
+        );
+    }
+
+    assert_eq!(
+        Tripod::snap_with_fraction(Rotation::new(2400)),
+        (Tripod::DownLeft, 0.0)
+    );
+}
+
+#[test]
+fn snap_with_fraction_between_partitions() {
+    // Halfway between North and NorthEast
+    let (partition, fraction) = CardinalOctant::snap_with_fraction(Rotation::new(225));
+    assert_eq!(partition, CardinalOctant::North);
+    assert!((fraction - 0.5).abs() < 0.01);
+
+    // Most of the way from NorthWest back around to North
+    let (partition, fraction) = CardinalOctant::snap_with_fraction(Rotation::from_degrees(351.0));
+    assert_eq!(partition, CardinalOctant::NorthWest);
+    assert!((fraction - 0.8).abs() < 0.01);
+
+    // Fractions are relative to the arc between adjacent partitions
+    let (partition, fraction) = Tripod::snap_with_fraction(Rotation::from_degrees(180.0));
+    assert_eq!(partition, Tripod::DownRight);
+    assert!((fraction - 0.5).abs() < 0.01);
+
+    let (partition, fraction) = Tripod::snap_with_fraction(Rotation::from_degrees(30.0));
+    assert_eq!(partition, Tripod::Up);
+    assert!((fraction - 0.25).abs() < 0.01);
+}
+
+#[test]
+fn distance_to_nearest_partition() {
+    // Rotations exactly on a partition are zero distance away
+    for partition in CardinalOctant::partitions() {
+        assert_eq!(
+            CardinalOctant::distance_to_nearest(Rotation::from(partition)),
+            Rotation::new(0)
+        );
+    }
+    assert_eq!(
+        Tripod::distance_to_nearest(Rotation::from_degrees(240.0)),
+        Rotation::new(0)
+    );
+
+    // The distance is measured to the nearest partition, in either direction
+    assert_eq!(
+        CardinalOctant::distance_to_nearest(Rotation::new(100)),
+        Rotation::new(100)
+    );
+    assert_eq!(
+        CardinalOctant::distance_to_nearest(Rotation::new(3500)),
+        Rotation::new(100)
+    );
+    assert_eq!(
+        Tripod::distance_to_nearest(Rotation::from_degrees(60.0)),
+        Rotation::from_degrees(60.0)
+    );
+    assert_eq!(
+        Tripod::distance_to_nearest(Rotation::from_degrees(300.0)),
+        Rotation::from_degrees(60.0)
+    );
+}
+
+#[test]
+fn from_direction_cardinals() {
+    assert_eq!(
+        CardinalOctant::from_direction(Direction::NORTH),
+        Some(CardinalOctant::North)
+    );
+    assert_eq!(
+        CardinalOctant::from_direction(Direction::EAST),
+        Some(CardinalOctant::East)
+    );
+    assert_eq!(
+        CardinalOctant::from_direction(Direction::SOUTH),
+        Some(CardinalOctant::South)
+    );
+    assert_eq!(
+        CardinalOctant::from_direction(Direction::WEST),
+        Some(CardinalOctant::West)
+    );
+
+    assert_eq!(Tripod::from_direction(Direction::NORTH), Some(Tripod::Up));
+    assert_eq!(
+        Tripod::from_direction(Direction::EAST),
+        Some(Tripod::DownRight)
+    );
+    assert_eq!(
+        Tripod::from_direction(Direction::WEST),
+        Some(Tripod::DownLeft)
+    );
+}
+
+#[test]
+fn from_direction_neutral() {
+    assert_eq!(CardinalOctant::from_direction(Direction::NEUTRAL), None);
+    assert_eq!(Tripod::from_direction(Direction::NEUTRAL), None);
+}
+
+#[test]
+fn from_direction_matches_snap() {
+    for degrees in [10.0, 44.0, 100.0, 200.0, 301.0] {
+        let direction = Direction::from(Rotation::from_degrees(degrees));
+
+        assert_eq!(
+            CardinalOctant::from_direction(direction),
+            Some(CardinalOctant::snap(direction))
+        );
+    }
+}
+
+#[test]
+fn partitions_with_rotations_match() {
+    fn assert_pairs_match<P: DirectionParitioning + PartialEq + core::fmt::Debug>() {
+        let pairs = P::partitions_with_rotations();
+        let (partitions, rotations): (Vec<P>, Vec<Rotation>) = pairs.into_iter().unzip();
+
+        assert_eq!(partitions, P::partitions());
+        assert_eq!(rotations, P::rotations());
+    }
+
+    assert_pairs_match::<CardinalOctant>();
+    assert_pairs_match::<Tripod>();
+
+    assert_eq!(
+        Tripod::partitions_with_rotations(),
+        vec![
+            (Tripod::Up, Rotation::NORTH),
+            (Tripod::DownRight, Rotation::from_degrees(120.0)),
+            (Tripod::DownLeft, Rotation::from_degrees(240.0)),
+        ]
+    );
+}
+
+#[test]
+fn directional_sprites_nearest_entry() {
+    // Unevenly spaced, with the largest gap wrapping through north
+    let sprites = DirectionalSprites {
+        entries: vec![
+            (Rotation::from_degrees(30.0), 'a'),
+            (Rotation::from_degrees(100.0), 'b'),
+            (Rotation::from_degrees(250.0), 'c'),
+        ],
+    };
+
+    assert_eq!(*sprites.get(Rotation::from_degrees(30.0)), 'a');
+    assert_eq!(*sprites.get(Rotation::from_degrees(60.0)), 'a');
+    assert_eq!(*sprites.get(Rotation::from_degrees(70.0)), 'b');
+    assert_eq!(*sprites.get(Rotation::from_degrees(170.0)), 'b');
+    assert_eq!(*sprites.get(Rotation::from_degrees(180.0)), 'c');
+    // Nearest across the wrap: 350 is 40 degrees from 30, but 100 degrees from 250
+    assert_eq!(*sprites.get(Rotation::from_degrees(350.0)), 'a');
+    assert_eq!(*sprites.get(Rotation::from_degrees(310.0)), 'c');
+}
+
+#[test]
+fn hexadecant_snapping_boundaries() {
+    // Each partition extends 11.25 degrees to either side
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(112)),
+        CardinalHexadecant::North
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(113)),
+        CardinalHexadecant::NorthNorthEast
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(337)),
+        CardinalHexadecant::NorthNorthEast
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(338)),
+        CardinalHexadecant::NorthEast
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(3488)),
+        CardinalHexadecant::North
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Rotation::new(3487)),
+        CardinalHexadecant::NorthNorthWest
+    );
+    assert_eq!(
+        CardinalHexadecant::snap(Direction::WEST_SOUTHWEST),
+        CardinalHexadecant::WestSouthWest
+    );
+}
+
+#[test]
+fn hexadecant_constants_are_consistent() {
+    let constants = [
+        (Rotation::NORTH_NORTHEAST, Direction::NORTH_NORTHEAST),
+        (Rotation::EAST_NORTHEAST, Direction::EAST_NORTHEAST),
+        (Rotation::EAST_SOUTHEAST, Direction::EAST_SOUTHEAST),
+        (Rotation::SOUTH_SOUTHEAST, Direction::SOUTH_SOUTHEAST),
+        (Rotation::SOUTH_SOUTHWEST, Direction::SOUTH_SOUTHWEST),
+        (Rotation::WEST_SOUTHWEST, Direction::WEST_SOUTHWEST),
+        (Rotation::WEST_NORTHWEST, Direction::WEST_NORTHWEST),
+        (Rotation::NORTH_NORTHWEST, Direction::NORTH_NORTHWEST),
+    ];
+
+    for (rotation, direction) in constants {
+        assert_eq!(Direction::from(rotation), direction);
+        rotation.assert_approx_eq(direction);
+        assert!((direction.unit_vector().length() - 1.0).abs() < 1e-6);
+    }
+
+    for partition in CardinalHexadecant::partitions() {
+        assert_eq!(
+            CardinalHexadecant::snap(Rotation::from(partition)),
+            partition
+        );
+    }
+}
+
+#[test]
+fn stick_inside_deadzone() {
+    assert_eq!(CardinalOctant::from_stick(Vec2::ZERO, 0.1), None);
+    assert_eq!(CardinalOctant::from_stick(Vec2::new(0.05, 0.05), 0.1), None);
+    // The deadzone is radial, so diagonals are filtered by their length rather than by each axis
+    assert_eq!(
+        CardinalOctant::from_stick(Vec2::new(0.08, -0.08), 0.12),
+        None
+    );
+    // Sticks exactly on the edge of the deadzone are ignored
+    assert_eq!(
+        CardinalQuadrant::from_stick(Vec2::new(0.0, -0.5), 0.5),
+        None
+    );
+    assert_eq!(
+        CardinalQuadrant::from_stick(Vec2::new(f32::NAN, 1.0), 0.1),
+        None
+    );
+}
+
+#[test]
+fn stick_outside_deadzone() {
+    assert_eq!(
+        CardinalOctant::from_stick(Vec2::new(0.7, 0.7), 0.1),
+        Some(CardinalOctant::NorthEast)
+    );
+    assert_eq!(
+        CardinalOctant::from_stick(Vec2::new(-0.15, 0.0), 0.1),
+        Some(CardinalOctant::West)
+    );
+    assert_eq!(
+        CardinalQuadrant::from_stick(Vec2::new(0.3, -0.9), 0.2),
+        Some(CardinalQuadrant::South)
+    );
+    // Without a deadzone, any non-zero deflection is snapped
+    assert_eq!(
+        CardinalQuadrant::from_stick(Vec2::new(0.001, 0.0), 0.0),
+        Some(CardinalQuadrant::East)
+    );
+}
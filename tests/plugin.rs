@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use core::fmt::Debug;
+use core::marker::PhantomData;
+use leafwing_2d::continuous::Saturating;
 use leafwing_2d::orientation::Direction;
+use leafwing_2d::plugin::{transform_desyncs, GameState, TwoDSystem};
 use leafwing_2d::prelude::*;
+use leafwing_2d::transform::{Plane, TwoDTransform};
 
 trait AppExtension {
     fn assert_component_eq<C: Component + PartialEq + Debug>(&mut self, value: &C);
@@ -50,8 +54,15 @@ impl AppExtension for App {
 }
 
 fn test_app() -> App {
+    test_app_with_policy(SyncPolicy::default())
+}
+
+fn test_app_with_policy(sync_policy: SyncPolicy) -> App {
     let mut app = App::new();
-    app.add_plugin(TwoDPlugin::default());
+    app.add_plugin(TwoDPlugin {
+        sync_policy,
+        ..Default::default()
+    });
     app.add_startup_system(test_entity);
     app.add_system_to_stage(CoreStage::Last, assert_orientation_matches);
     app.add_system_to_stage(CoreStage::Last, assert_position_matches);
@@ -70,6 +81,11 @@ fn assert_orientation_matches(query: Query<(Option<&Rotation>, Option<&Direction
         }
 
         if let Some(&direction) = maybe_direction {
+            // Neutral directions are deliberately left out of sync
+            if direction.is_neutral() {
+                continue;
+            }
+
             transform.rotation.assert_approx_eq(direction);
         }
     }
@@ -187,3 +203,607 @@ fn sync_position() {
     app.update();
     app.assert_positionlike_approx_eq(Transform::from_xyz(4.0, 4.0, 5.0));
 }
+
+#[test]
+fn neutral_direction_preserved() {
+    let mut app = test_app();
+
+    // Run startup systems
+    app.update();
+
+    // Setting a neutral direction does not change the rotation
+    app.set_component(Rotation::EAST);
+    app.update();
+    app.set_component(Direction::NEUTRAL);
+    app.update();
+    app.assert_component_eq(&Direction::NEUTRAL);
+    app.assert_orientation_approx_eq(Rotation::EAST);
+
+    // Changing rotation does not resurrect the direction
+    app.set_component(Rotation::WEST);
+    app.update();
+    app.assert_component_eq(&Direction::NEUTRAL);
+    app.assert_orientation_approx_eq(Transform::from_rotation(Rotation::WEST.into()));
+
+    // Changing transform does not resurrect the direction
+    app.set_component(Transform::from_rotation(Rotation::SOUTH.into()));
+    app.update();
+    app.update();
+    app.assert_component_eq(&Direction::NEUTRAL);
+    app.assert_orientation_approx_eq(Rotation::SOUTH);
+
+    // Explicitly overwriting the direction resumes synchronization
+    app.set_component(Direction::NORTH);
+    app.update();
+    app.assert_orientation_approx_eq(Rotation::NORTH);
+    app.assert_orientation_approx_eq(Transform::from_rotation(Rotation::NORTH.into()));
+}
+
+#[test]
+fn default_bundle_is_consistent() {
+    let bundle = TwoDBundle::<F32>::default();
+    assert_eq!(bundle.rotation, Rotation::NORTH);
+    assert_eq!(bundle.direction, Direction::NORTH);
+    assert_eq!(Direction::from(bundle.rotation), bundle.direction);
+
+    let mut app = test_app();
+    app.update();
+    app.update();
+
+    app.assert_component_eq(&Rotation::NORTH);
+    app.assert_component_eq(&Direction::NORTH);
+}
+
+#[test]
+fn two_d_authoritative_policy() {
+    let mut app = test_app_with_policy(SyncPolicy::TwoDAuthoritative);
+
+    // Run startup systems
+    app.update();
+
+    // Changing transform, position and rotation (2D wins)
+    app.set_component(Transform {
+        translation: Vec3::new(0.0, 42.0, 0.0),
+        rotation: Rotation::NORTH.into(),
+        ..Default::default()
+    });
+    app.set_component(Position {
+        x: F32(3.0),
+        y: F32(3.0),
+    });
+    app.set_component(Rotation::SOUTH);
+    app.update();
+    app.update();
+
+    app.assert_positionlike_approx_eq(Position {
+        x: F32(3.0),
+        y: F32(3.0),
+    });
+    app.assert_orientation_approx_eq(Rotation::SOUTH);
+    app.assert_orientation_approx_eq(Direction::SOUTH);
+}
+
+#[test]
+fn transform_authoritative_policy() {
+    let mut app = test_app_with_policy(SyncPolicy::TransformAuthoritative);
+
+    // Run startup systems
+    app.update();
+
+    // Changing only the 2D components still updates the transform
+    app.set_component(Position {
+        x: F32(1.0),
+        y: F32(1.0),
+    });
+    app.update();
+    app.assert_positionlike_approx_eq(Transform::from_xyz(1.0, 1.0, 0.0));
+
+    // Changing transform, position and rotation (transform wins)
+    app.set_component(Transform {
+        translation: Vec3::new(0.0, 42.0, 0.0),
+        rotation: Rotation::SOUTH.into(),
+        ..Default::default()
+    });
+    app.set_component(Position {
+        x: F32(3.0),
+        y: F32(3.0),
+    });
+    app.set_component(Rotation::EAST);
+    app.update();
+    app.update();
+
+    app.assert_positionlike_approx_eq(Position {
+        x: F32(0.0),
+        y: F32(42.0),
+    });
+    app.assert_orientation_approx_eq(Rotation::SOUTH);
+    app.assert_orientation_approx_eq(Direction::SOUTH);
+}
+
+#[test]
+fn transform_authoritative_keeps_same_frame_2d_changes() {
+    let mut app = test_app_with_policy(SyncPolicy::TransformAuthoritative);
+
+    // Run startup systems
+    app.update();
+
+    // Changing rotation and position together, without touching the transform
+    app.set_component(Rotation::EAST);
+    app.set_component(Position {
+        x: F32(5.0),
+        y: F32(-2.0),
+    });
+    app.update();
+
+    // Writing the rotation onto the transform must not make the position look overridden
+    app.assert_positionlike_approx_eq(Position {
+        x: F32(5.0),
+        y: F32(-2.0),
+    });
+    app.assert_positionlike_approx_eq(Transform::from_xyz(5.0, -2.0, 0.0));
+    app.assert_orientation_approx_eq(Rotation::EAST);
+    app.assert_orientation_approx_eq(Direction::EAST);
+}
+
+#[test]
+fn saturating_position_clamped() {
+    type Bounded = Saturating<-10, 10>;
+
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin::<Bounded, GameState, CoreStage> {
+        kinematics: false,
+        kinematics_state: None,
+        sync_policy: SyncPolicy::default(),
+        plane: Plane::Xy,
+        spatial_hash: None,
+        stage: CoreStage::PostUpdate,
+        coordinate_type: PhantomData,
+    });
+    app.world
+        .spawn()
+        .insert(Position::<Bounded>::default())
+        .insert(Transform::default());
+    app.update();
+
+    // Pushing the transform beyond the bounds
+    app.set_component(Transform::from_xyz(42.0, -42.0, 0.0));
+    app.update();
+    app.assert_component_eq(&Position {
+        x: Bounded::MAX,
+        y: Bounded::MIN,
+    });
+
+    // Values within the bounds are unaffected
+    app.set_component(Transform::from_xyz(2.5, -3.0, 0.0));
+    app.update();
+    app.assert_component_eq(&Position {
+        x: Bounded::from(2.5),
+        y: Bounded::from(-3.0),
+    });
+}
+
+#[test]
+fn two_d_transform_matches_sync() {
+    let mut app = test_app();
+
+    // Run startup systems
+    app.update();
+
+    // Pushing 2D components onto the transform
+    let position = Position::<F32>::new(2.0, 5.0);
+    app.set_component(position);
+    app.set_component(Rotation::SOUTHEAST);
+    app.update();
+
+    let expected: Transform = TwoDTransform::new(position, Rotation::SOUTHEAST).into();
+    app.assert_positionlike_approx_eq(expected);
+    app.assert_orientation_approx_eq(expected);
+
+    // Pulling the transform onto the 2D components
+    let transform = Transform {
+        translation: Vec3::new(-7.0, 1.5, 0.0),
+        rotation: Rotation::from_degrees(123.4).into(),
+        ..Default::default()
+    };
+    app.set_component(transform);
+    app.update();
+
+    let expected = TwoDTransform::<F32>::from(&transform);
+    app.assert_component_eq(&expected.position);
+    app.assert_component_eq(&expected.rotation);
+}
+
+#[test]
+fn only_changed_entities_are_synced() {
+    let mut app = test_app();
+    let entities: Vec<Entity> = (0..10)
+        .map(|_| {
+            app.world
+                .spawn()
+                .insert_bundle(TwoDBundle::<F32>::default())
+                .insert(Transform::default())
+                .id()
+        })
+        .collect();
+    app.update();
+
+    // Changing the 2D components of one entity
+    *app.world.get_mut::<Rotation>(entities[0]).unwrap() = Rotation::EAST;
+    *app.world.get_mut::<Position<F32>>(entities[0]).unwrap() = Position::new(1.0, 2.0);
+    // Changing the transform of another
+    *app.world.get_mut::<Transform>(entities[1]).unwrap() =
+        Transform::from_xyz(3.0, 4.0, 0.0).with_rotation(Rotation::SOUTH.into());
+    app.update();
+
+    let transform = app.world.get::<Transform>(entities[0]).unwrap();
+    transform.rotation.assert_approx_eq(Rotation::EAST);
+    assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 0.0));
+
+    app.world
+        .get::<Rotation>(entities[1])
+        .unwrap()
+        .assert_approx_eq(Rotation::SOUTH);
+    assert_eq!(
+        *app.world.get::<Position<F32>>(entities[1]).unwrap(),
+        Position::new(3.0, 4.0)
+    );
+
+    // Unchanged entities are left alone
+    for &entity in &entities[2..] {
+        assert_eq!(
+            *app.world.get::<Transform>(entity).unwrap(),
+            Transform::default()
+        );
+        assert_eq!(*app.world.get::<Rotation>(entity).unwrap(), Rotation::NORTH);
+    }
+}
+
+fn move_east(mut query: Query<(&mut Position<F32>, &mut Rotation)>) {
+    for (mut position, mut rotation) in query.iter_mut() {
+        position.x += F32(1.0);
+        *rotation = Rotation::EAST;
+    }
+}
+
+#[test]
+fn integrate_label_writes_reach_transform() {
+    let mut app = test_app();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        move_east.label(TwoDSystem::Integrate),
+    );
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::default())
+        .id();
+
+    for frame in 1..=3 {
+        app.update();
+
+        let transform = app.world.get::<Transform>(entity).unwrap();
+        assert_eq!(transform.translation.x, frame as f32);
+        transform.rotation.assert_approx_eq(Rotation::EAST);
+        app.world
+            .get::<Direction>(entity)
+            .unwrap()
+            .assert_approx_eq(Direction::EAST);
+    }
+}
+
+#[test]
+fn writes_before_sync_transform_reach_transform() {
+    let mut app = test_app();
+    app.add_system_to_stage(
+        CoreStage::PostUpdate,
+        move_east.before(TwoDSystem::SyncTransform),
+    );
+    let entity = app
+        .world
+        .spawn()
+        .insert(Position::<F32>::default())
+        .insert(Rotation::default())
+        .insert(Transform::default())
+        .id();
+
+    app.update();
+
+    let transform = app.world.get::<Transform>(entity).unwrap();
+    assert_eq!(transform.translation.x, 1.0);
+    transform.rotation.assert_approx_eq(Rotation::EAST);
+}
+
+#[test]
+fn spawned_transform_initializes_2d_components() {
+    let mut app = test_app();
+    let transform = Transform {
+        translation: Vec3::new(5.0, -2.0, 1.0),
+        rotation: Rotation::from_degrees(123.4).into(),
+        ..Default::default()
+    };
+
+    let entity = app
+        .world
+        .spawn()
+        .insert(transform)
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .id();
+    app.update();
+
+    // The 2D components are initialized from the transform, rather than overwriting it
+    app.world
+        .get::<Rotation>(entity)
+        .unwrap()
+        .assert_approx_eq(Rotation::from_degrees(123.4));
+    app.world
+        .get::<Direction>(entity)
+        .unwrap()
+        .assert_approx_eq(Direction::from(Rotation::from_degrees(123.4)));
+    assert_eq!(
+        *app.world.get::<Position<F32>>(entity).unwrap(),
+        Position::new(5.0, -2.0)
+    );
+
+    let synced_transform = app.world.get::<Transform>(entity).unwrap();
+    synced_transform
+        .rotation
+        .assert_approx_eq(transform.rotation);
+    assert_eq!(synced_transform.translation, transform.translation);
+}
+
+#[test]
+fn explicit_2d_components_win_at_spawn() {
+    let mut app = test_app();
+
+    let entity = app
+        .world
+        .spawn()
+        .insert(Transform::from_rotation(Rotation::SOUTH.into()))
+        .insert_bundle(TwoDBundle::<F32> {
+            rotation: Rotation::EAST,
+            direction: Direction::EAST,
+            ..Default::default()
+        })
+        .id();
+    app.update();
+
+    assert_eq!(*app.world.get::<Rotation>(entity).unwrap(), Rotation::EAST);
+    app.world
+        .get::<Transform>(entity)
+        .unwrap()
+        .rotation
+        .assert_approx_eq(Rotation::EAST);
+}
+
+#[test]
+fn transform_desyncs_reported() {
+    let mut app = test_app();
+    let max_rotation_error = Rotation::new(5);
+    let max_position_error = 0.01;
+
+    let synced = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::default())
+        .id();
+    let desynced = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::default())
+        .id();
+    app.update();
+
+    assert!(
+        transform_desyncs::<F32>(&mut app.world, max_rotation_error, max_position_error).is_empty()
+    );
+
+    // Writing to the transform after syncing has run desyncs the entity
+    app.world
+        .get_mut::<Transform>(desynced)
+        .unwrap()
+        .translation
+        .x = 3.0;
+    assert_eq!(
+        transform_desyncs::<F32>(&mut app.world, max_rotation_error, max_position_error),
+        vec![desynced]
+    );
+
+    // Rotation errors are reported too, but only beyond the tolerance
+    app.world.get_mut::<Transform>(synced).unwrap().rotation = Rotation::new(3).into();
+    assert_eq!(
+        transform_desyncs::<F32>(&mut app.world, max_rotation_error, max_position_error),
+        vec![desynced]
+    );
+    app.world.get_mut::<Transform>(synced).unwrap().rotation = Rotation::EAST.into();
+    let mut desyncs =
+        transform_desyncs::<F32>(&mut app.world, max_rotation_error, max_position_error);
+    desyncs.sort();
+    assert_eq!(desyncs, vec![synced, desynced]);
+
+    // Nothing is modified
+    assert_eq!(
+        app.world.get::<Position<F32>>(desynced),
+        Some(&Position::default())
+    );
+    assert_eq!(app.world.get::<Rotation>(synced), Some(&Rotation::NORTH));
+
+    // The next sync repairs both entities
+    app.update();
+    assert!(
+        transform_desyncs::<F32>(&mut app.world, max_rotation_error, max_position_error).is_empty()
+    );
+}
+
+fn plane_test_app(plane: Plane) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        plane,
+        ..Default::default()
+    });
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::from_xyz(0.0, 0.0, 5.0))
+        .id();
+    app.update();
+
+    *app.world.get_mut::<Position<F32>>(entity).unwrap() = Position::new(1.0, 2.0);
+    *app.world.get_mut::<Rotation>(entity).unwrap() = Rotation::EAST;
+    app.update();
+
+    (app, entity)
+}
+
+#[test]
+fn xy_plane_moves_x_and_y() {
+    let (app, entity) = plane_test_app(Plane::Xy);
+    let transform = *app.world.get::<Transform>(entity).unwrap();
+
+    assert_eq!(transform.translation, Vec3::new(1.0, 2.0, 5.0));
+    // Facing east, so the sprite's local up points along +x
+    assert!((transform.rotation * Vec3::Y).abs_diff_eq(Vec3::X, 0.01));
+}
+
+#[test]
+fn xz_plane_moves_x_and_z() {
+    let (mut app, entity) = plane_test_app(Plane::Xz);
+    let transform = *app.world.get::<Transform>(entity).unwrap();
+
+    // North is -z, and the y-value is left untouched
+    assert_eq!(transform.translation, Vec3::new(1.0, 0.0, -2.0));
+    // Facing east, so the forward direction points along +x, turning around the y axis
+    assert!((transform.rotation * -Vec3::Z).abs_diff_eq(Vec3::X, 0.01));
+    assert!((transform.rotation * Vec3::Y).abs_diff_eq(Vec3::Y, 0.01));
+
+    // Changes to the transform are read back from the same axes
+    app.world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(3.0, 7.0, 4.0);
+    app.update();
+    assert_eq!(
+        *app.world.get::<Position<F32>>(entity).unwrap(),
+        Position::new(3.0, -4.0)
+    );
+    app.world
+        .get::<Rotation>(entity)
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+}
+
+/// The number of entities that had each 2D component or their [`Transform`] changed, per frame
+#[derive(Default)]
+struct ChangeCounts(Vec<usize>);
+
+fn count_changes(
+    mut counts: ResMut<ChangeCounts>,
+    rotation_query: Query<(), Changed<Rotation>>,
+    direction_query: Query<(), Changed<Direction>>,
+    position_query: Query<(), Changed<Position<F32>>>,
+    transform_query: Query<(), Changed<Transform>>,
+) {
+    counts.0.push(
+        rotation_query.iter().count()
+            + direction_query.iter().count()
+            + position_query.iter().count()
+            + transform_query.iter().count(),
+    );
+}
+
+#[test]
+fn entities_at_rest_are_not_changed() {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    })
+    .init_resource::<ChangeCounts>()
+    .add_system_to_stage(CoreStage::Last, count_changes);
+
+    app.world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32> {
+            rotation: Rotation::from_degrees(37.0),
+            direction: Rotation::from_degrees(37.0).into(),
+            position: Position::new(1.0, 2.0),
+            ..Default::default()
+        })
+        .insert(Transform::default());
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    // Everything is changed when the entity is spawned, but never again
+    let counts = &app.world.resource::<ChangeCounts>().0;
+    assert_ne!(counts[0], 0);
+    assert_eq!(counts[1..], [0; 9]);
+}
+
+#[test]
+fn child_rotation_is_relative_to_parent() {
+    let mut app = App::new();
+    app.add_plugin(HierarchyPlugin)
+        .add_plugin(TransformPlugin)
+        .add_plugin(TwoDPlugin {
+            kinematics: false,
+            ..Default::default()
+        });
+
+    let turret = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32> {
+            rotation: Rotation::EAST,
+            direction: Direction::EAST,
+            position: Position::new(0.0, 1.0),
+            ..Default::default()
+        })
+        .insert_bundle(TransformBundle::default())
+        .id();
+
+    let hull = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32> {
+            rotation: Rotation::EAST,
+            direction: Direction::EAST,
+            position: Position::new(5.0, 0.0),
+            ..Default::default()
+        })
+        .insert_bundle(TransformBundle::default())
+        .push_children(&[turret])
+        .id();
+
+    app.update();
+
+    // The turret's own components and transform stay relative to the hull
+    assert_eq!(*app.world.get::<Rotation>(turret).unwrap(), Rotation::EAST);
+    app.world
+        .get::<Transform>(turret)
+        .unwrap()
+        .rotation
+        .assert_approx_eq(Rotation::EAST);
+
+    // In world space, the rotations are composed
+    let hull_global = *app.world.get::<GlobalTransform>(hull).unwrap();
+    let turret_global = *app.world.get::<GlobalTransform>(turret).unwrap();
+    hull_global.rotation.assert_approx_eq(Rotation::EAST);
+    turret_global.rotation.assert_approx_eq(Rotation::SOUTH);
+    // The turret's offset is rotated along with the hull
+    assert!(turret_global
+        .translation
+        .abs_diff_eq(Vec3::new(6.0, 0.0, 0.0), 0.01));
+
+    // Turning the hull turns the turret with it
+    *app.world.get_mut::<Rotation>(hull).unwrap() = Rotation::SOUTH;
+    app.update();
+    app.world
+        .get::<GlobalTransform>(turret)
+        .unwrap()
+        .rotation
+        .assert_approx_eq(Rotation::WEST);
+}
@@ -0,0 +1,638 @@
+use bevy::math::Vec2;
+use bevy::transform::components::Transform;
+use leafwing_2d::continuous::{Saturating, F32};
+use leafwing_2d::discrete::{AdjacentGrid, DiscreteCoordinate, OrthogonalGrid};
+use leafwing_2d::errors::CoordinateConversionError;
+use leafwing_2d::orientation::{Direction, Orientation, Rotation};
+use leafwing_2d::position::{Position, Positionlike, Positions};
+
+#[test]
+fn direction_and_distance_to() {
+    let start = Position::<F32>::new(1.0, 2.0);
+    let target = Position::<F32>::new(4.0, 6.0);
+
+    let (direction, distance) = start.direction_and_distance_to(target);
+    direction.assert_approx_eq(Direction::new(Vec2::new(3.0, 4.0)));
+    assert!((direction.unit_vector() - Vec2::new(0.6, 0.8)).length() < 1e-6);
+    assert!((distance - 5.0).abs() < 1e-6);
+
+    // Reversing the positions flips the direction, but not the distance
+    let (reverse_direction, reverse_distance) = target.direction_and_distance_to(start);
+    reverse_direction.assert_approx_eq(-direction);
+    assert_eq!(reverse_distance, distance);
+
+    // Discrete coordinates work too
+    let (direction, distance) = Position::<OrthogonalGrid>::new(0, 0)
+        .direction_and_distance_to(Position::<OrthogonalGrid>::new(-2, 0));
+    direction.assert_approx_eq(Direction::WEST);
+    assert!((distance - 2.0).abs() < 1e-6);
+}
+
+#[test]
+fn direction_and_distance_to_self() {
+    let position = Position::<F32>::new(3.0, -7.0);
+
+    assert_eq!(
+        position.direction_and_distance_to(position),
+        (Direction::NEUTRAL, 0.0)
+    );
+}
+
+#[test]
+fn add_vec2() {
+    let position = Position::<F32>::new(1.0, 2.0);
+    assert_eq!(position + Vec2::new(0.5, -3.0), Position::new(1.5, -1.0));
+
+    let mut position = position;
+    position += Vec2::new(-1.0, 1.0);
+    assert_eq!(position, Position::new(0.0, 3.0));
+}
+
+#[test]
+fn sub_vec2() {
+    let position = Position::<F32>::new(1.0, 2.0);
+    assert_eq!(position - Vec2::new(0.5, -3.0), Position::new(0.5, 5.0));
+
+    let mut position = position;
+    position -= Vec2::new(-1.0, 1.0);
+    assert_eq!(position, Position::new(2.0, 1.0));
+}
+
+#[test]
+fn offset_to() {
+    let start = Position::<F32>::new(1.0, 2.0);
+    let end = Position::<F32>::new(-2.0, 6.0);
+
+    assert_eq!(start.offset_to(end), Vec2::new(-3.0, 4.0));
+    assert_eq!(end.offset_to(start), Vec2::new(3.0, -4.0));
+    assert_eq!(start + start.offset_to(end), end);
+}
+
+#[test]
+fn discrete_vec2_arithmetic_rounds() {
+    let cell = Position::<OrthogonalGrid>::new(1, 1);
+
+    assert_eq!(cell + Vec2::new(0.6, -0.4), Position::new(2, 1));
+    assert_eq!(cell - Vec2::new(0.6, -0.4), Position::new(0, 1));
+    assert_eq!(cell + Vec2::new(2.2, -1.8), Position::new(3, -1));
+
+    assert_eq!(cell.offset_to(Position::new(-2, 5)), Vec2::new(-3.0, 4.0));
+}
+
+#[test]
+fn wrapped_path_is_shorter() {
+    let world_size = Vec2::new(100.0, 60.0);
+    let start = Position::<F32>::new(10.0, 30.0);
+    let target = Position::<F32>::new(90.0, 30.0);
+
+    // The direct path is 80 units long, but wrapping around the left edge is only 20
+    assert_eq!(start.offset_to(target), Vec2::new(80.0, 0.0));
+    assert_eq!(
+        start.offset_to_wrapped(target, world_size),
+        Vec2::new(-20.0, 0.0)
+    );
+    start
+        .direction_to_wrapped(target, world_size)
+        .assert_approx_eq(Direction::WEST);
+    target
+        .direction_to_wrapped(start, world_size)
+        .assert_approx_eq(Direction::EAST);
+
+    // Both axes wrap independently
+    let corner = Position::<F32>::new(95.0, 55.0);
+    let origin = Position::<F32>::new(5.0, 5.0);
+    assert_eq!(
+        corner.offset_to_wrapped(origin, world_size),
+        Vec2::new(10.0, 10.0)
+    );
+    corner
+        .direction_to_wrapped(origin, world_size)
+        .assert_approx_eq(Direction::NORTHEAST);
+}
+
+#[test]
+fn wrapped_path_matches_direct_path_when_shorter() {
+    let world_size = Vec2::new(100.0, 100.0);
+    let start = Position::<F32>::new(10.0, 10.0);
+    let target = Position::<F32>::new(30.0, -20.0);
+
+    assert_eq!(
+        start.offset_to_wrapped(target, world_size),
+        start.offset_to(target)
+    );
+
+    // Non-positive world sizes disable wrapping along that axis
+    let far = Position::<F32>::new(500.0, 90.0);
+    assert_eq!(
+        start.offset_to_wrapped(far, Vec2::new(0.0, 100.0)),
+        Vec2::new(490.0, -20.0)
+    );
+
+    assert_eq!(
+        start.direction_to_wrapped(start + Vec2::new(100.0, 0.0), world_size),
+        Direction::NEUTRAL
+    );
+}
+
+#[test]
+fn offset_in_direction() {
+    let origin = Position::<F32>::default();
+
+    origin
+        .offset(Direction::NORTH, 5.0)
+        .assert_approx_eq(Position::<F32>::new(0.0, 5.0));
+    origin
+        .offset(Direction::SOUTHWEST, 2.0_f32.sqrt())
+        .assert_approx_eq(Position::<F32>::new(-1.0, -1.0));
+
+    // Negative distances move backwards
+    Position::<F32>::new(1.0, 1.0)
+        .offset(Direction::EAST, -3.0)
+        .assert_approx_eq(Position::<F32>::new(-2.0, 1.0));
+
+    assert_eq!(origin.offset(Direction::NEUTRAL, 5.0), origin);
+}
+
+#[test]
+fn offset_discrete_position() {
+    let cell = Position::<OrthogonalGrid>::new(2, 3);
+
+    assert_eq!(cell.offset(Direction::WEST, 2.0), Position::new(0, 3));
+    assert_eq!(cell.offset(Direction::NEUTRAL, 2.0), cell);
+}
+
+#[test]
+fn try_from_transform_in_range() {
+    let transform = Transform::from_xyz(1.4, -2.6, 7.0);
+
+    assert_eq!(
+        Position::<F32>::try_from_transform(&transform),
+        Ok(Position::new(1.4, -2.6))
+    );
+    // Discrete coordinates are still rounded
+    assert_eq!(
+        Position::<OrthogonalGrid>::try_from_transform(&transform),
+        Ok(Position::new(1, -3))
+    );
+}
+
+#[test]
+fn try_from_transform_out_of_range() {
+    // Silently saturates to `isize::MAX` when converted with `From`
+    let transform = Transform::from_xyz(0.0, 1e20, 0.0);
+    assert_eq!(
+        Position::<OrthogonalGrid>::try_from_transform(&transform),
+        Err(CoordinateConversionError::OutOfRange { value: 1e20 })
+    );
+
+    let transform = Transform::from_xyz(-11.0, 0.0, 0.0);
+    assert_eq!(
+        Position::<Saturating<-10, 10>>::try_from_transform(&transform),
+        Err(CoordinateConversionError::OutOfRange { value: -11.0 })
+    );
+}
+
+#[test]
+fn try_from_transform_non_finite() {
+    let transform = Transform::from_xyz(f32::NAN, 0.0, 0.0);
+    assert!(matches!(
+        Position::<F32>::try_from_transform(&transform),
+        Err(CoordinateConversionError::NonFinite { value }) if value.is_nan()
+    ));
+
+    let transform = Transform::from_xyz(0.0, f32::NEG_INFINITY, 0.0);
+    assert_eq!(
+        Position::<OrthogonalGrid>::try_from_transform(&transform),
+        Err(CoordinateConversionError::NonFinite {
+            value: f32::NEG_INFINITY
+        })
+    );
+}
+
+#[test]
+fn von_neumann_neighbors_of_origin() {
+    let neighbors = Position::<OrthogonalGrid>::default().von_neumann_neighbors(OrthogonalGrid(1));
+
+    assert_eq!(
+        neighbors,
+        [
+            Position::new(0, 1),
+            Position::new(1, 0),
+            Position::new(0, -1),
+            Position::new(-1, 0),
+        ]
+    );
+
+    // Matches the neighbors of the equivalent grid
+    assert_eq!(
+        neighbors.to_vec(),
+        OrthogonalGrid::neighbors(Position::default())
+    );
+}
+
+#[test]
+fn moore_neighbors_of_origin() {
+    let neighbors = Position::<AdjacentGrid>::default().moore_neighbors(AdjacentGrid(1));
+
+    assert_eq!(
+        neighbors,
+        [
+            Position::new(0, 1),
+            Position::new(1, 1),
+            Position::new(1, 0),
+            Position::new(1, -1),
+            Position::new(0, -1),
+            Position::new(-1, -1),
+            Position::new(-1, 0),
+            Position::new(-1, 1),
+        ]
+    );
+
+    assert_eq!(
+        neighbors.to_vec(),
+        AdjacentGrid::neighbors(Position::default())
+    );
+}
+
+#[test]
+fn neighbors_with_larger_steps() {
+    let center = Position::<F32>::new(1.0, 2.0);
+
+    assert_eq!(
+        center.von_neumann_neighbors(F32(0.5)),
+        [
+            Position::new(1.0, 2.5),
+            Position::new(1.5, 2.0),
+            Position::new(1.0, 1.5),
+            Position::new(0.5, 2.0),
+        ]
+    );
+
+    let moore = center.moore_neighbors(F32(2.0));
+    assert_eq!(moore[3], Position::new(3.0, 0.0));
+    assert_eq!(moore[7], Position::new(-1.0, 4.0));
+}
+
+#[test]
+fn positions_buffer_round_trip() {
+    let vec2s: Vec<Vec2> = (0..100).map(|i| Vec2::new(i as f32, -(i as f32))).collect();
+    let positions: Positions = vec2s.iter().copied().collect();
+
+    assert_eq!(positions.len(), 100);
+    assert_eq!(positions.iter().len(), 100);
+    assert_eq!(positions.to_vec2s(), vec2s);
+    assert_eq!(positions.get(7), Some(Vec2::new(7.0, -7.0)));
+    assert_eq!(positions.get(100), None);
+    assert_eq!(positions.xs()[3], 3.0);
+    assert_eq!(positions.ys()[3], -3.0);
+
+    assert!(Positions::new().is_empty());
+}
+
+#[test]
+fn positions_buffer_integration() {
+    let mut positions: Positions = [Vec2::new(1.0, 1.0), Vec2::new(-2.0, 3.0)]
+        .into_iter()
+        .collect();
+    let velocities = [Vec2::new(4.0, 0.0), Vec2::new(-1.0, -2.0)];
+
+    positions.integrate(&velocities, 0.25);
+    assert_eq!(
+        positions.to_vec2s(),
+        vec![Vec2::new(2.0, 1.0), Vec2::new(-2.25, 2.5)]
+    );
+
+    // Matches integrating each position separately
+    let mut position = Position::<F32>::new(1.0, 1.0);
+    position += velocities[0] * 0.25;
+    assert_eq!(Vec2::from(position), positions.get(0).unwrap());
+}
+
+#[test]
+#[should_panic]
+fn positions_buffer_mismatched_velocities() {
+    let mut positions: Positions = [Vec2::ZERO].into_iter().collect();
+    positions.integrate(&[], 1.0);
+}
+
+#[test]
+fn cast_between_coordinate_types() {
+    let world_position = Position::<F32>::new(1.6, 2.4);
+
+    // Discrete coordinates round to the nearest tile
+    let tile: Position<OrthogonalGrid> = world_position.cast();
+    assert_eq!(tile, Position::new(OrthogonalGrid(2), OrthogonalGrid(2)));
+    assert_eq!(tile.cast::<F32>(), Position::new(2.0, 2.0));
+
+    let negative_tile: Position<OrthogonalGrid> = Position::<F32>::new(-1.6, -0.4).cast();
+    assert_eq!(
+        negative_tile,
+        Position::new(OrthogonalGrid(-2), OrthogonalGrid(0))
+    );
+
+    // Bounded coordinates clamp
+    let clamped: Position<Saturating<-1, 1>> = world_position.cast();
+    assert_eq!(Vec2::from(clamped), Vec2::new(1.0, 1.0));
+
+    // Casting to the same type is lossless
+    assert_eq!(world_position.cast::<F32>(), world_position);
+}
+
+#[test]
+fn index_row_boundaries() {
+    let width = 4;
+
+    // The end of the first row is followed by the start of the second
+    assert_eq!(
+        Position::<OrthogonalGrid>::new(3, 0).to_index(width),
+        Some(3)
+    );
+    assert_eq!(
+        Position::<OrthogonalGrid>::new(0, 1).to_index(width),
+        Some(4)
+    );
+    assert_eq!(
+        Position::<OrthogonalGrid>::from_index(3, width),
+        Some(Position::new(3, 0))
+    );
+    assert_eq!(
+        Position::<OrthogonalGrid>::from_index(4, width),
+        Some(Position::new(0, 1))
+    );
+
+    // Positions past the end of a row do not wrap onto the next row
+    assert_eq!(Position::<OrthogonalGrid>::new(4, 0).to_index(width), None);
+
+    // Negative coordinates lie outside of the grid
+    assert_eq!(Position::<OrthogonalGrid>::new(-1, 1).to_index(width), None);
+    assert_eq!(Position::<OrthogonalGrid>::new(1, -1).to_index(width), None);
+
+    // Zero-width grids have no tiles
+    assert_eq!(Position::<OrthogonalGrid>::new(0, 0).to_index(0), None);
+    assert_eq!(Position::<OrthogonalGrid>::from_index(0, 0), None);
+}
+
+#[test]
+fn index_round_trip() {
+    let width = 5;
+
+    for index in 0..50 {
+        let position = Position::<AdjacentGrid>::from_index(index, width).unwrap();
+        assert_eq!(position.to_index(width), Some(index));
+    }
+
+    assert_eq!(
+        Position::<OrthogonalGrid>::new(isize::MAX, isize::MAX).to_index(usize::MAX),
+        None
+    );
+}
+
+#[test]
+fn turn_to_face_target() {
+    let position = Position::<F32>::new(2.0, 3.0);
+
+    // The target is 90 degrees clockwise of the current facing
+    assert_eq!(
+        position.turn_to_face(Rotation::NORTH, Position::new(5.0, 3.0)),
+        Ok(900)
+    );
+    assert_eq!(
+        position.turn_to_face(Rotation::WEST, Position::new(2.0, 10.0)),
+        Ok(900)
+    );
+
+    // The shortest turn is used, even across north
+    assert_eq!(
+        position.turn_to_face(Rotation::from_degrees(10.0), Position::new(2.0, 10.0)),
+        Ok(-100)
+    );
+    assert_eq!(
+        position.turn_to_face(Rotation::from_degrees(350.0), Position::new(2.0, 10.0)),
+        Ok(100)
+    );
+
+    // Already facing the target
+    assert_eq!(
+        position.turn_to_face(Rotation::SOUTH, Position::new(2.0, -1.0)),
+        Ok(0)
+    );
+
+    // Targets directly behind are reached by turning clockwise
+    assert_eq!(
+        position.turn_to_face(Rotation::EAST, Position::new(0.0, 3.0)),
+        Ok(1800)
+    );
+}
+
+#[test]
+fn turn_to_face_coincident_target() {
+    let position = Position::<F32>::new(2.0, 3.0);
+
+    assert!(position.turn_to_face(Rotation::NORTH, position).is_err());
+}
+
+#[test]
+fn weighted_distance() {
+    let start = Position::<F32>::new(1.0, -2.0);
+    let target = Position::<F32>::new(7.0, 14.0);
+
+    // The offset of (6, 16) is squashed to (6, 8)
+    assert_eq!(start.weighted_distance(target, Vec2::new(1.0, 0.5)), 10.0);
+    assert_eq!(target.weighted_distance(start, Vec2::new(1.0, 0.5)), 10.0);
+
+    // Unit weights give the Euclidean distance
+    let (_, distance) = start.direction_and_distance_to(target);
+    assert!((start.weighted_distance(target, Vec2::ONE) - distance).abs() < 1e-5);
+
+    // Zero weights ignore that axis entirely
+    assert_eq!(start.weighted_distance(target, Vec2::new(0.0, 1.0)), 16.0);
+}
+
+#[test]
+fn try_direction_to_distinct_positions() {
+    let origin = Position::<F32>::new(1.0, 1.0);
+
+    assert_eq!(
+        origin.try_direction_to(Position::new(1.0, 4.0)),
+        Some(Direction::NORTH)
+    );
+    assert_eq!(
+        origin.try_direction_to(Position::new(-2.0, 1.0)),
+        Some(Direction::WEST)
+    );
+    origin
+        .try_direction_to(Position::new(2.0, 0.0))
+        .unwrap()
+        .assert_approx_eq(Direction::SOUTHEAST);
+}
+
+#[test]
+fn try_direction_to_coincident_positions() {
+    let origin = Position::<F32>::new(1.0, 1.0);
+    assert_eq!(origin.try_direction_to(origin), None);
+
+    let cell = Position::<OrthogonalGrid>::new(2, 3);
+    assert_eq!(cell.try_direction_to(cell), None);
+}
+
+#[test]
+fn approx_eq_within_epsilon() {
+    let position = Position::<F32>::new(10.0, -10.0);
+    let epsilon = Vec2::new(0.01, 0.25);
+
+    assert!(position.approx_eq(position, Vec2::ZERO));
+    assert!(position.approx_eq(Position::new(10.005, -10.2), epsilon));
+    assert!(position.approx_eq(Position::new(9.995, -9.8), epsilon));
+    // The comparison is symmetric
+    assert!(Position::new(9.995, -9.8).approx_eq(position, epsilon));
+}
+
+#[test]
+fn approx_eq_outside_epsilon() {
+    let position = Position::<F32>::new(10.0, -10.0);
+    let epsilon = Vec2::new(0.01, 0.25);
+
+    // Each axis is checked against its own epsilon
+    assert!(!position.approx_eq(Position::new(10.02, -10.0), epsilon));
+    assert!(!position.approx_eq(Position::new(10.0, -10.3), epsilon));
+    assert!(!position.approx_eq(Position::new(10.1, -10.0), Vec2::new(0.05, 1.0)));
+}
+
+#[test]
+fn rotation_to_cursor() {
+    let player = Position::<F32>::new(1.0, 1.0);
+
+    player
+        .rotation_to_cursor(Vec2::new(1.0, -5.0))
+        .unwrap()
+        .assert_approx_eq(Rotation::SOUTH);
+    player
+        .rotation_to_cursor(Vec2::new(4.0, 1.0))
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+
+    let cell = Position::<OrthogonalGrid>::new(2, 3);
+    cell.rotation_to_cursor(Vec2::new(2.0, 10.0))
+        .unwrap()
+        .assert_approx_eq(Rotation::NORTH);
+}
+
+#[test]
+fn rotation_to_cursor_on_position() {
+    let player = Position::<F32>::new(1.0, 1.0);
+
+    assert!(player.rotation_to_cursor(Vec2::new(1.0, 1.0)).is_err());
+}
+
+#[test]
+fn signed_area_of_unit_square() {
+    let counterclockwise = [
+        Position::<F32>::new(0.0, 0.0),
+        Position::new(1.0, 0.0),
+        Position::new(1.0, 1.0),
+        Position::new(0.0, 1.0),
+    ];
+    let clockwise = [
+        Position::<F32>::new(0.0, 0.0),
+        Position::new(0.0, 1.0),
+        Position::new(1.0, 1.0),
+        Position::new(1.0, 0.0),
+    ];
+
+    assert_eq!(Position::signed_area(&counterclockwise), 1.0);
+    assert_eq!(Position::signed_area(&clockwise), -1.0);
+
+    assert!(!Position::is_clockwise(&counterclockwise));
+    assert!(Position::is_clockwise(&clockwise));
+}
+
+#[test]
+fn signed_area_is_translation_invariant() {
+    let square = [
+        Position::<OrthogonalGrid>::new(5, -3),
+        Position::new(6, -3),
+        Position::new(6, -2),
+        Position::new(5, -2),
+    ];
+
+    assert_eq!(Position::signed_area(&square), 1.0);
+    assert!(!Position::is_clockwise(&square));
+}
+
+#[test]
+fn signed_area_of_degenerate_polygons() {
+    let line = [Position::<F32>::new(0.0, 0.0), Position::new(1.0, 1.0)];
+    let collinear = [
+        Position::<F32>::new(0.0, 0.0),
+        Position::new(1.0, 1.0),
+        Position::new(2.0, 2.0),
+    ];
+
+    assert_eq!(Position::<F32>::signed_area(&[]), 0.0);
+    assert_eq!(Position::signed_area(&line), 0.0);
+    assert_eq!(Position::signed_area(&collinear), 0.0);
+
+    assert!(!Position::is_clockwise(&line));
+    assert!(!Position::is_clockwise(&collinear));
+}
+
+#[test]
+fn on_circle_cardinal_points() {
+    let center = Position::<F32>::new(3.0, -2.0);
+    let epsilon = Vec2::splat(1e-4);
+
+    let north = Position::on_circle(center, 4.0, Rotation::NORTH);
+    let east = Position::on_circle(center, 4.0, Rotation::EAST);
+    let south = Position::on_circle(center, 4.0, Rotation::SOUTH);
+    let west = Position::on_circle(center, 4.0, Rotation::WEST);
+
+    assert!(north.approx_eq(Position::new(3.0, 2.0), epsilon));
+    assert!(east.approx_eq(Position::new(7.0, -2.0), epsilon));
+    assert!(south.approx_eq(Position::new(3.0, -6.0), epsilon));
+    assert!(west.approx_eq(Position::new(-1.0, -2.0), epsilon));
+}
+
+#[test]
+fn on_circle_with_zero_radius() {
+    let center = Position::<F32>::new(3.0, -2.0);
+
+    assert_eq!(
+        Position::on_circle(center, 0.0, Rotation::new(1234)),
+        center
+    );
+}
+
+#[test]
+fn on_circle_on_grid() {
+    let center = Position::<OrthogonalGrid>::new(5, 5);
+
+    assert_eq!(
+        Position::on_circle(center, 2.0, Rotation::SOUTH),
+        Position::new(5, 3)
+    );
+}
+
+#[test]
+fn advance_east_from_origin() {
+    let origin = Position::<F32>::default();
+
+    let advanced = origin.advance(Rotation::EAST, 3.0);
+    assert!(advanced.approx_eq(Position::new(3.0, 0.0), Vec2::splat(1e-4)));
+
+    let reversed = origin.advance(Rotation::EAST, -3.0);
+    assert!(reversed.approx_eq(Position::new(-3.0, 0.0), Vec2::splat(1e-4)));
+}
+
+#[test]
+fn advance_matches_offset() {
+    let start = Position::<F32>::new(-4.0, 7.0);
+
+    for deci_degrees in (0..3600).step_by(150) {
+        let rotation = Rotation::new(deci_degrees);
+        let direction: Direction = rotation.into();
+
+        assert!(start
+            .advance(rotation, 2.5)
+            .approx_eq(start.offset(direction, 2.5), Vec2::splat(1e-4)));
+    }
+}
@@ -1,5 +1,6 @@
-use bevy::math::Vec3;
+use bevy::math::{Vec2, Vec3};
 use leafwing_2d::continuous::F32;
+use leafwing_2d::discrete::OrthogonalGrid;
 use leafwing_2d::position::Position;
 
 #[test]
@@ -72,3 +73,61 @@ fn vec3_to_position() {
         Vec3::new(-42., 3., 17.).try_into()
     );
 }
+
+#[test]
+fn position_to_vec2() {
+    assert_eq!(Vec2::from(Position::<F32>::new(0., 0.)), Vec2::new(0., 0.));
+    assert_eq!(Vec2::from(Position::<F32>::new(1., 0.)), Vec2::new(1., 0.));
+    assert_eq!(
+        Vec2::from(Position::<F32>::new(-42., 3.)),
+        Vec2::new(-42., 3.)
+    );
+
+    assert_eq!(
+        Vec2::from(Position::<OrthogonalGrid>::new(
+            OrthogonalGrid(-42),
+            OrthogonalGrid(3)
+        )),
+        Vec2::new(-42., 3.)
+    );
+}
+
+#[test]
+fn vec2_to_position() {
+    assert_eq!(
+        Position::<F32>::from(Vec2::new(0., 0.)),
+        Position::new(0., 0.)
+    );
+    assert_eq!(
+        Position::<F32>::from(Vec2::new(1., 0.)),
+        Position::new(1., 0.)
+    );
+    assert_eq!(
+        Position::<F32>::from(Vec2::new(-42., 3.)),
+        Position::new(-42., 3.)
+    );
+
+    // Discrete coordinates are rounded to the nearest cell
+    assert_eq!(
+        Position::<OrthogonalGrid>::from(Vec2::new(-42.2, 2.7)),
+        Position::new(OrthogonalGrid(-42), OrthogonalGrid(3))
+    );
+}
+
+#[test]
+fn tuple_round_trip() {
+    let position = Position::<F32>::new(-42., 3.);
+
+    let tuple: (F32, F32) = position.into();
+    assert_eq!(tuple, (F32(-42.), F32(3.)));
+    assert_eq!(Position::from(tuple), position);
+}
+
+#[test]
+fn array_round_trip() {
+    let position = Position::<F32>::new(-42., 3.);
+
+    let array: [F32; 2] = position.into();
+    assert_eq!(array, [F32(-42.), F32(3.)]);
+    assert_eq!(Position::from(array), position);
+}
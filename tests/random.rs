@@ -0,0 +1,57 @@
+#![cfg(feature = "rand")]
+
+use leafwing_2d::orientation::{Direction, Rotation};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const N_SAMPLES: usize = 40_000;
+
+/// Each quadrant should receive a quarter of the samples, give or take 5%
+fn assert_uniform_quadrants(quadrant_counts: [usize; 4]) {
+    let expected = N_SAMPLES as f32 / 4.0;
+
+    for (quadrant, &count) in quadrant_counts.iter().enumerate() {
+        let relative_error = (count as f32 - expected).abs() / expected;
+        assert!(
+            relative_error < 0.05,
+            "Quadrant {quadrant} received {count} of {N_SAMPLES} samples: {quadrant_counts:?}"
+        );
+    }
+}
+
+#[test]
+fn random_rotations_are_uniform() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut quadrant_counts = [0; 4];
+    let mut total_deci_degrees = 0.0;
+
+    for _ in 0..N_SAMPLES {
+        let rotation = Rotation::random(&mut rng);
+        assert!(rotation.deci_degrees() < Rotation::FULL_CIRCLE);
+
+        quadrant_counts[(rotation.deci_degrees() / 900) as usize] += 1;
+        total_deci_degrees += rotation.deci_degrees() as f32;
+    }
+
+    assert_uniform_quadrants(quadrant_counts);
+
+    // The mean of a uniform distribution over 0..3600 is 1799.5
+    let mean = total_deci_degrees / N_SAMPLES as f32;
+    assert!((mean - 1799.5).abs() < 30.0, "The mean was {mean}");
+}
+
+#[test]
+fn random_directions_are_uniform() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut quadrant_counts = [0; 4];
+
+    for _ in 0..N_SAMPLES {
+        let direction = Direction::random(&mut rng);
+        assert!((direction.unit_vector().length() - 1.0).abs() < 1e-5);
+
+        let rotation: Rotation = direction.into();
+        quadrant_counts[(rotation.deci_degrees() / 900) as usize] += 1;
+    }
+
+    assert_uniform_quadrants(quadrant_counts);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "rapier")]
+
+use bevy_rapier2d::rapier::na::UnitComplex;
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use leafwing_2d::orientation::{Orientation, Rotation};
+
+#[test]
+fn cardinal_rotations_into_rapier() {
+    assert_eq!(Rotation::EAST.into_rapier_angle(), 0.0);
+    assert!((Rotation::NORTH.into_rapier_angle() - FRAC_PI_2).abs() < 0.001);
+    assert!((Rotation::WEST.into_rapier_angle() - PI).abs() < 0.001);
+    assert!((Rotation::SOUTH.into_rapier_angle() + FRAC_PI_2).abs() < 0.001);
+}
+
+#[test]
+fn rapier_angles_into_rotations() {
+    Rotation::from_rapier_angle(0.0).assert_approx_eq(Rotation::EAST);
+    Rotation::from_rapier_angle(FRAC_PI_2).assert_approx_eq(Rotation::NORTH);
+    Rotation::from_rapier_angle(PI).assert_approx_eq(Rotation::WEST);
+    Rotation::from_rapier_angle(-FRAC_PI_2).assert_approx_eq(Rotation::SOUTH);
+    Rotation::from_rapier_angle(FRAC_PI_4).assert_approx_eq(Rotation::from_degrees(45.0));
+}
+
+#[test]
+fn rapier_angles_stay_within_half_turn() {
+    for deci_degrees in (0..3600).step_by(25) {
+        let angle = Rotation::new(deci_degrees).into_rapier_angle();
+        assert!(angle > -PI - 0.001 && angle <= PI + 0.001);
+    }
+}
+
+#[test]
+fn unit_complex_round_trip() {
+    for deci_degrees in (0..3600).step_by(25) {
+        let rotation = Rotation::new(deci_degrees);
+        let unit_complex: UnitComplex<f32> = rotation.into();
+
+        Rotation::from(unit_complex).assert_approx_eq(rotation);
+    }
+}
+
+#[test]
+fn unit_complex_matches_rotation_vector() {
+    for deci_degrees in (0..3600).step_by(25) {
+        let rotation = Rotation::new(deci_degrees);
+        let unit_complex: UnitComplex<f32> = rotation.into();
+        let vec2 = rotation.into_vec2();
+
+        assert!((unit_complex.cos_angle() - vec2.x).abs() < 0.001);
+        assert!((unit_complex.sin_angle() - vec2.y).abs() < 0.001);
+    }
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "reflect")]
+
+use bevy::prelude::*;
+use bevy::reflect::{FromReflect, Reflect, TypeRegistryArc};
+use leafwing_2d::partitioning::{CardinalOctant, CardinalQuadrant, DirectionParitioning};
+use leafwing_2d::prelude::*;
+
+#[test]
+fn cardinal_octant_round_trips_through_reflection() {
+    for partition in CardinalOctant::partitions() {
+        let reflected: Box<dyn Reflect> = partition.clone_value();
+
+        assert_eq!(
+            CardinalOctant::from_reflect(reflected.as_ref()),
+            Some(partition)
+        );
+        assert_eq!(reflected.reflect_partial_eq(&partition), Some(true));
+    }
+
+    // Other types are not mistaken for partitions
+    let quadrant: Box<dyn Reflect> = CardinalQuadrant::North.clone_value();
+    assert_eq!(CardinalOctant::from_reflect(quadrant.as_ref()), None);
+}
+
+#[test]
+fn partitions_are_registered() {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    });
+
+    let registry = app.world.resource::<TypeRegistryArc>().read();
+    let registration = registry
+        .get_with_name(std::any::type_name::<CardinalOctant>())
+        .expect("CardinalOctant should be registered by `TwoDPlugin`");
+
+    assert_eq!(
+        registration.type_id(),
+        std::any::TypeId::of::<CardinalOctant>()
+    );
+}
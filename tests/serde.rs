@@ -0,0 +1,64 @@
+#![cfg(feature = "serde")]
+
+use leafwing_2d::partitioning::{
+    CardinalHexadecant, CardinalOctant, CardinalQuadrant, CardinalSextant, DirectionParitioning,
+    OffsetQuadrant, OffsetSextant,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::fmt::Debug;
+
+fn assert_round_trips<
+    P: DirectionParitioning + Serialize + DeserializeOwned + PartialEq + Debug,
+>() {
+    for partition in P::partitions() {
+        let serialized = serde_json::to_string(&partition).unwrap();
+        let deserialized: P = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized, partition);
+    }
+}
+
+#[test]
+fn partitions_round_trip() {
+    assert_round_trips::<CardinalQuadrant>();
+    assert_round_trips::<OffsetQuadrant>();
+    assert_round_trips::<CardinalOctant>();
+    assert_round_trips::<CardinalHexadecant>();
+    assert_round_trips::<CardinalSextant>();
+    assert_round_trips::<OffsetSextant>();
+}
+
+#[test]
+fn partitions_serialize_as_variant_names() {
+    assert_eq!(
+        serde_json::to_string(&CardinalOctant::NorthEast).unwrap(),
+        "\"NorthEast\""
+    );
+    assert_eq!(
+        serde_json::to_string(&CardinalQuadrant::West).unwrap(),
+        "\"West\""
+    );
+    assert_eq!(
+        serde_json::to_string(&CardinalHexadecant::SouthSouthWest).unwrap(),
+        "\"SouthSouthWest\""
+    );
+}
+
+#[test]
+fn partitions_deserialize_from_abbreviations() {
+    let octant: CardinalOctant = serde_json::from_str("\"NE\"").unwrap();
+    assert_eq!(octant, CardinalOctant::NorthEast);
+
+    let quadrant: CardinalQuadrant = serde_json::from_str("\"S\"").unwrap();
+    assert_eq!(quadrant, CardinalQuadrant::South);
+
+    let offset: OffsetQuadrant = serde_json::from_str("\"SW\"").unwrap();
+    assert_eq!(offset, OffsetQuadrant::SouthWest);
+
+    let hexadecant: CardinalHexadecant = serde_json::from_str("\"ENE\"").unwrap();
+    assert_eq!(hexadecant, CardinalHexadecant::EastNorthEast);
+
+    // Abbreviations for directions outside of the partitioning are rejected
+    assert!(serde_json::from_str::<CardinalQuadrant>("\"NE\"").is_err());
+    assert!(serde_json::from_str::<CardinalSextant>("\"E\"").is_err());
+}
@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use leafwing_2d::orientation::Direction;
+use leafwing_2d::prelude::*;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    })
+    .add_plugin(SnapToPlugin::<CardinalOctant>::default());
+
+    app
+}
+
+#[test]
+fn rotation_snaps_to_partition() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Transform::default())
+        .insert(Rotation::from_degrees(50.0))
+        .insert(SnapTo::<CardinalOctant>::default())
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<Rotation>(entity).unwrap(),
+        Rotation::from_degrees(45.0)
+    );
+    assert_eq!(
+        *app.world.get::<Direction>(entity).unwrap(),
+        Direction::NORTHEAST
+    );
+    let transform = *app.world.get::<Transform>(entity).unwrap();
+    Rotation::from(transform.rotation).assert_approx_eq(Rotation::from_degrees(45.0));
+}
+
+#[test]
+fn direction_snaps_to_partition() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(SnapTo::<CardinalOctant>::default())
+        .id();
+
+    app.update();
+    *app.world.get_mut::<Direction>(entity).unwrap() = Direction::new(Vec2::new(1.0, -0.2));
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<Direction>(entity).unwrap(),
+        Direction::EAST
+    );
+    app.world
+        .get::<Rotation>(entity)
+        .unwrap()
+        .assert_approx_eq(Rotation::EAST);
+}
+
+#[test]
+fn entities_without_snap_to_are_untouched() {
+    let mut app = test_app();
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(Rotation::from_degrees(50.0))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        *app.world.get::<Rotation>(entity).unwrap(),
+        Rotation::from_degrees(50.0)
+    );
+}
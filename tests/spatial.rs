@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use leafwing_2d::prelude::*;
+use leafwing_2d::spatial::SpatialHash;
+use std::collections::HashSet;
+
+#[test]
+fn query_radius_includes_only_nearby_entities() {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        spatial_hash: Some(4.0),
+        ..Default::default()
+    });
+
+    let mut spawn_at = |x: f32, y: f32| {
+        app.world
+            .spawn()
+            .insert_bundle(TwoDBundle::<F32> {
+                position: Position::new(x, y),
+                ..Default::default()
+            })
+            .id()
+    };
+
+    let origin = spawn_at(0.0, 0.0);
+    let nearby = spawn_at(3.0, -4.0);
+    let across_cells = spawn_at(-2.0, 4.5);
+    let far = spawn_at(10.0, 10.0);
+    let just_outside = spawn_at(5.1, 0.0);
+
+    app.update();
+
+    let spatial_hash = app.world.resource::<SpatialHash<F32>>();
+    assert_eq!(spatial_hash.len(), 5);
+
+    let found: HashSet<Entity> = spatial_hash
+        .query_radius(Position::new(0.0, 0.0), 5.0)
+        .collect();
+
+    assert_eq!(found, HashSet::from([origin, nearby, across_cells]));
+    assert!(!found.contains(&far));
+    assert!(!found.contains(&just_outside));
+}
+
+#[test]
+fn spatial_hash_tracks_movement() {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        spatial_hash: Some(1.0),
+        ..Default::default()
+    });
+
+    let entity = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .id();
+    app.update();
+
+    *app.world.get_mut::<Position<F32>>(entity).unwrap() = Position::new(-20.0, 20.0);
+    app.update();
+
+    let spatial_hash = app.world.resource::<SpatialHash<F32>>();
+    assert_eq!(spatial_hash.len(), 1);
+    assert_eq!(
+        spatial_hash.query_radius(Position::default(), 1.0).count(),
+        0
+    );
+    assert_eq!(
+        spatial_hash
+            .query_radius(Position::new(-20.5, 20.0), 1.0)
+            .collect::<Vec<_>>(),
+        vec![entity]
+    );
+}
+
+#[test]
+fn spatial_hash_is_disabled_by_default() {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    });
+    app.update();
+
+    assert!(app.world.get_resource::<SpatialHash<F32>>().is_none());
+}
+
+#[test]
+fn query_radius_handles_radii_far_larger_than_cells() {
+    let mut spatial_hash = SpatialHash::<F32>::new(1.0);
+    let near = Entity::from_raw(0);
+    let far = Entity::from_raw(1);
+    let very_far = Entity::from_raw(2);
+    spatial_hash.insert(near, Position::new(0.5, -0.5));
+    spatial_hash.insert(far, Position::new(-900_000.0, 400_000.0));
+    spatial_hash.insert(very_far, Position::new(3e9, 3e9));
+
+    let found: HashSet<Entity> = spatial_hash
+        .query_radius(Position::default(), 1e6)
+        .collect();
+    assert_eq!(found, HashSet::from([near, far]));
+
+    let found: HashSet<Entity> = spatial_hash
+        .query_radius(Position::default(), f32::INFINITY)
+        .collect();
+    assert_eq!(found, HashSet::from([near, far, very_far]));
+}
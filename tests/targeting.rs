@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use leafwing_2d::orientation::Direction;
+use leafwing_2d::prelude::*;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugin(TwoDPlugin {
+        kinematics: false,
+        ..Default::default()
+    });
+
+    app
+}
+
+/// Spawns a target at `target_position`, and a north-facing tracker at the origin
+fn spawn_tracker_and_target(app: &mut App, target_position: Position<F32>) -> (Entity, Entity) {
+    let target = app.world.spawn().insert(target_position).id();
+
+    let tracker = app
+        .world
+        .spawn()
+        .insert_bundle(TwoDBundle::<F32>::default())
+        .insert(FaceTarget {
+            target,
+            max_turn_rate: Rotation::from_degrees(30.0),
+        })
+        .id();
+
+    (tracker, target)
+}
+
+fn rotation(app: &App, entity: Entity) -> Rotation {
+    *app.world.get::<Rotation>(entity).unwrap()
+}
+
+#[test]
+fn tracker_turns_towards_target() {
+    let mut app = test_app();
+    let (tracker, _target) = spawn_tracker_and_target(&mut app, Position::new(10.0, 0.0));
+
+    // Turning is limited by the `max_turn_rate`
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::from_degrees(30.0));
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::from_degrees(60.0));
+
+    // The tracker reaches the target, but never overshoots it
+    for _ in 0..5 {
+        app.update();
+        rotation(&app, tracker).assert_approx_eq(Rotation::EAST);
+    }
+
+    // Direction is kept in sync
+    app.world
+        .get::<Direction>(tracker)
+        .unwrap()
+        .assert_approx_eq(Direction::EAST);
+}
+
+#[test]
+fn tracker_takes_the_short_way_around() {
+    let mut app = test_app();
+    let (tracker, _target) = spawn_tracker_and_target(&mut app, Position::new(-10.0, 10.0));
+
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::from_degrees(-30.0));
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::NORTHWEST);
+}
+
+#[test]
+fn tracker_follows_moving_target() {
+    let mut app = test_app();
+    let (tracker, target) = spawn_tracker_and_target(&mut app, Position::new(0.0, 10.0));
+
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::NORTH);
+
+    *app.world.get_mut::<Position<F32>>(target).unwrap() = Position::new(0.0, -10.0);
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::from_degrees(30.0));
+}
+
+#[test]
+fn despawned_target_is_ignored() {
+    let mut app = test_app();
+    let (tracker, target) = spawn_tracker_and_target(&mut app, Position::new(10.0, 0.0));
+
+    app.update();
+    app.world.despawn(target);
+
+    app.update();
+    app.update();
+    rotation(&app, tracker).assert_approx_eq(Rotation::from_degrees(30.0));
+}
+
+#[test]
+fn coincident_target_is_ignored() {
+    let mut app = test_app();
+    let (tracker, _target) = spawn_tracker_and_target(&mut app, Position::new(0.0, 0.0));
+
+    app.update();
+    assert_eq!(rotation(&app, tracker), Rotation::NORTH);
+}
@@ -14,6 +14,21 @@ fn main() {
         .run()
         .expect("Please fix failing tests in output above.");
 
+    // Run tests again, using the pure-Rust `libm` backend for orientation math
+    cmd!("cargo test --features libm")
+        .run()
+        .expect("Please fix failing tests with the `libm` feature in output above.");
+
+    // Build the orientation math without `std`, for a target that has no `std` at all
+    cmd!("cargo build --no-default-features --features libm --target thumbv7em-none-eabihf")
+        .run()
+        .expect("Please fix the `no_std` build errors in output above.");
+
+    // Run the tests for the `approx` trait impls, random sampling, reflection, desync warnings and rapier conversions
+    cmd!("cargo test --features approx,rand,reflect,desync_warnings,rapier")
+        .run()
+        .expect("Please fix failing tests with the `approx`, `rand`, `reflect`, `desync_warnings` and `rapier` features in output above.");
+
     // Run doc tests: these are ignored by `cargo test`
     cmd!("cargo test --doc --workspace")
         .run()